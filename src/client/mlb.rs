@@ -1,14 +1,30 @@
-use crate::model::mlb::{
-    GameContentResponse, GameLinescoreResponse, Schedule, ScheduleResponse, Team, TeamsResponse,
+use crate::{
+    model::mlb::{
+        Division, DivisionsResponse, GameBoxscoreResponse, GameContentResponse, GameEvent,
+        GameFeedDiff, GameFeedResponse, GameLinescoreResponse, Leader, LeaderCategory, League,
+        LeagueLeadersResponse, LeaguesResponse, LiveGameFeed, PeopleResponse, Person, PersonStats,
+        PersonStatsResponse, RosterEntry, RosterResponse, RosterType, Schedule, ScheduleQuery,
+        ScheduleResponse, Season, SeasonsResponse, StandingsRecord, StandingsResponse,
+        StandingsType, StatsQuery, Team, TeamStats, TeamStatsResponse, TeamsResponse, Venue,
+        VenuesResponse,
+    },
+    Error,
 };
-use failure::{format_err, Error, ResultExt};
-use futures::AsyncReadExt;
+use futures::{stream, AsyncReadExt, Stream};
+use futures_timer::Delay;
 use isahc::{
+    config::Configurable,
     http::{self, Uri},
-    AsyncBody, HttpClient, Request,
+    AsyncBody, AsyncReadResponseExt, HttpClient, Request,
 };
 use serde::de::DeserializeOwned;
-use std::collections::HashMap;
+use std::{
+    collections::{HashMap, VecDeque},
+    time::Duration,
+};
+
+/// Venues only include their name and link unless hydrated
+const VENUE_HYDRATIONS: &str = "location,timezone,fieldInfo";
 
 pub struct Client {
     client: HttpClient,
@@ -21,16 +37,28 @@ impl Client {
         Client::default()
     }
 
-    fn get_url(&self, path: &str, params: Option<HashMap<&str, String>>) -> http::Uri {
-        if let Some(params) = params {
-            let params = serde_urlencoded::to_string(params).unwrap_or_else(|_| String::from(""));
-            let uri = format!("{}/{}?{}", self.base, path, params);
-            uri.parse::<Uri>().unwrap()
-        } else {
-            let uri = format!("{}/{}", self.base, path);
-            uri.parse::<Uri>().unwrap()
-        }
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::default()
     }
+
+    fn get_url(&self, path: &str, params: Option<HashMap<&str, String>>) -> Result<Uri, Error> {
+        url_with_params(&self.base, path, params)
+    }
+
+    /// The live feed is only served from `v1.1` of the API
+    fn get_feed_url(
+        &self,
+        path: &str,
+        params: Option<HashMap<&str, String>>,
+    ) -> Result<Uri, Error> {
+        let base = match self.base.strip_suffix("/v1") {
+            Some(base) => format!("{}/v1.1", base),
+            None => self.base.clone(),
+        };
+
+        url_with_params(&base, path, params)
+    }
+
     async fn get<T: DeserializeOwned>(&self, url: Uri) -> Result<T, Error> {
         let request = Request::builder()
             .method("GET")
@@ -38,24 +66,28 @@ impl Client {
             .body(AsyncBody::empty())
             .unwrap();
 
-        let res = self
-            .client
-            .send_async(request)
-            .await
-            .context("Failed to get request")?;
+        let mut res = self.client.send_async(request).await?;
+
+        if !res.status().is_success() {
+            let code = res.status().as_u16();
+            let body = res.text().await.unwrap_or_default();
+            return Err(Error::from_status(code, body));
+        }
 
         let mut body = res.into_body();
         let mut bytes = Vec::new();
         body.read_to_end(&mut bytes).await?;
 
-        Ok(serde_json::from_slice(&bytes)?)
+        let de = &mut serde_json::Deserializer::from_slice(&bytes);
+
+        Ok(serde_path_to_error::deserialize(de)?)
     }
 
     pub async fn get_teams(&self) -> Result<Vec<Team>, Error> {
         let mut modifiers = HashMap::new();
         modifiers.insert("sportId", String::from(&self.sport));
 
-        let url = self.get_url("teams", Some(modifiers));
+        let url = self.get_url("teams", Some(modifiers))?;
 
         let response = self.get::<TeamsResponse>(url).await?;
 
@@ -67,7 +99,7 @@ impl Client {
     /// Usefull during Exhibition games since an MLB team can
     /// face off against a college team
     pub async fn get_all_teams(&self) -> Result<Vec<Team>, Error> {
-        let url = self.get_url("teams", None);
+        let url = self.get_url("teams", None)?;
 
         let response = self.get::<TeamsResponse>(url).await?;
 
@@ -75,30 +107,256 @@ impl Client {
     }
 
     pub async fn get_team(&self, team_id: u32) -> Result<Team, Error> {
-        let url = self.get_url(&format!("teams/{}", team_id), None);
+        let url = self.get_url(&format!("teams/{}", team_id), None)?;
 
         let mut response = self.get::<TeamsResponse>(url).await?;
 
-        let team = response
-            .teams
-            .pop()
-            .ok_or_else(|| format_err!("Failed to get team response."))?;
+        let team = response.teams.pop().ok_or(Error::NoTeams)?;
 
         Ok(team)
     }
 
+    pub async fn get_leagues(&self) -> Result<Vec<League>, Error> {
+        let mut modifiers = HashMap::new();
+        modifiers.insert("sportId", String::from(&self.sport));
+
+        let url = self.get_url("league", Some(modifiers))?;
+
+        let response = self.get::<LeaguesResponse>(url).await?;
+
+        Ok(response.leagues)
+    }
+
+    /// Get a league by the `id` found on teams and standings records
+    pub async fn get_league(&self, league_id: u32) -> Result<League, Error> {
+        let url = self.get_url(&format!("league/{}", league_id), None)?;
+
+        let mut response = self.get::<LeaguesResponse>(url).await?;
+
+        let league = response.leagues.pop().ok_or(Error::NoLeagues)?;
+
+        Ok(league)
+    }
+
+    pub async fn get_divisions(&self, sport: Sport) -> Result<Vec<Division>, Error> {
+        let mut modifiers = HashMap::new();
+        modifiers.insert("sportId", String::from(&sport));
+
+        let url = self.get_url("divisions", Some(modifiers))?;
+
+        let response = self.get::<DivisionsResponse>(url).await?;
+
+        Ok(response.divisions)
+    }
+
+    /// Get a division by the `id` found on teams and standings records
+    pub async fn get_division(&self, division_id: u32) -> Result<Division, Error> {
+        let url = self.get_url(&format!("divisions/{}", division_id), None)?;
+
+        let mut response = self.get::<DivisionsResponse>(url).await?;
+
+        let division = response.divisions.pop().ok_or(Error::NoDivisions)?;
+
+        Ok(division)
+    }
+
+    /// Get venues for the client's sport with location, time zone and field info
+    pub async fn get_venues(&self) -> Result<Vec<Venue>, Error> {
+        let mut modifiers = HashMap::new();
+        modifiers.insert("sportIds", String::from(&self.sport));
+        modifiers.insert("hydrate", String::from(VENUE_HYDRATIONS));
+
+        let url = self.get_url("venues", Some(modifiers))?;
+
+        let response = self.get::<VenuesResponse>(url).await?;
+
+        Ok(response.venues)
+    }
+
+    /// Get a venue with location, time zone and field info
+    pub async fn get_venue(&self, venue_id: u32) -> Result<Venue, Error> {
+        let mut modifiers = HashMap::new();
+        modifiers.insert("hydrate", String::from(VENUE_HYDRATIONS));
+
+        let url = self.get_url(&format!("venues/{}", venue_id), Some(modifiers))?;
+
+        let mut response = self.get::<VenuesResponse>(url).await?;
+
+        let venue = response.venues.pop().ok_or(Error::NoVenues)?;
+
+        Ok(venue)
+    }
+
+    /// Get every season for the client's sport
+    pub async fn get_seasons(&self) -> Result<Vec<Season>, Error> {
+        let mut modifiers = HashMap::new();
+        modifiers.insert("sportId", String::from(&self.sport));
+
+        let url = self.get_url("seasons/all", Some(modifiers))?;
+
+        let response = self.get::<SeasonsResponse>(url).await?;
+
+        Ok(response.seasons)
+    }
+
+    pub async fn get_current_season(&self) -> Result<Season, Error> {
+        let mut modifiers = HashMap::new();
+        modifiers.insert("sportId", String::from(&self.sport));
+
+        let url = self.get_url("seasons", Some(modifiers))?;
+
+        let mut response = self.get::<SeasonsResponse>(url).await?;
+
+        let season = response.seasons.pop().ok_or(Error::NoSeasons)?;
+
+        Ok(season)
+    }
+
+    /// Get the season `date` belongs to, see [`Season::for_date`]
+    ///
+    /// Fetches every season on each call, when resolving many dates call
+    /// `get_seasons` once and use `Season::for_date` instead.
+    pub async fn season_for_date(&self, date: chrono::NaiveDate) -> Result<Option<Season>, Error> {
+        let seasons = self.get_seasons().await?;
+
+        Ok(Season::for_date(&seasons, date).cloned())
+    }
+
+    /// Get the minor league affiliates of a major league club, ordered by
+    /// [`Sport::level`] from Triple-A down to Rookie
+    ///
+    /// Affiliates outside that hierarchy, such as an alternate training site,
+    /// come last. Use [`Sport::from_id`] on each team's `sport` to find its level.
+    pub async fn get_affiliates(&self, team_id: u32) -> Result<Vec<Team>, Error> {
+        let mut modifiers = HashMap::new();
+        modifiers.insert("teamIds", team_id.to_string());
+
+        let url = self.get_url("teams/affiliates", Some(modifiers))?;
+
+        let response = self.get::<TeamsResponse>(url).await?;
+
+        let mut affiliates: Vec<Team> = response
+            .teams
+            .into_iter()
+            .filter(|team| team.id != team_id)
+            .collect();
+        affiliates.sort_by_key(|team| {
+            team.sport
+                .as_ref()
+                .and_then(|sport| Sport::from_id(sport.id))
+                .map_or(u8::MAX, |sport| sport.level())
+        });
+
+        Ok(affiliates)
+    }
+
+    /// Get season hitting and pitching totals for a team along with their
+    /// league ranks
+    ///
+    /// Defaults to the current season when `season`, such as "2019", isn't provided
+    pub async fn get_team_stats(
+        &self,
+        team_id: u32,
+        season: Option<&str>,
+    ) -> Result<Vec<TeamStats>, Error> {
+        let mut modifiers = HashMap::new();
+        modifiers.insert(
+            "stats",
+            String::from("statsSingleSeason,regularSeasonStatRankings"),
+        );
+        modifiers.insert("group", String::from("hitting,pitching"));
+        if let Some(season) = season {
+            modifiers.insert("season", season.to_owned());
+        }
+
+        let url = self.get_url(&format!("teams/{}/stats", team_id), Some(modifiers))?;
+
+        let response = self.get::<TeamStatsResponse>(url).await?;
+
+        Ok(response.stats)
+    }
+
+    /// Defaults to the current season when `season`, such as "2019", isn't provided
+    pub async fn get_team_roster(
+        &self,
+        team_id: u32,
+        roster_type: RosterType,
+        season: Option<&str>,
+    ) -> Result<Vec<RosterEntry>, Error> {
+        let mut modifiers = HashMap::new();
+        modifiers.insert("rosterType", String::from(&roster_type));
+        if let Some(season) = season {
+            modifiers.insert("season", season.to_owned());
+        }
+
+        let url = self.get_url(&format!("teams/{}/roster", team_id), Some(modifiers))?;
+
+        let response = self.get::<RosterResponse>(url).await?;
+
+        Ok(response.roster)
+    }
+
+    pub async fn get_person(&self, person_id: u32) -> Result<Person, Error> {
+        let mut modifiers = HashMap::new();
+        modifiers.insert("hydrate", String::from("currentTeam"));
+
+        let url = self.get_url(&format!("people/{}", person_id), Some(modifiers))?;
+
+        let mut response = self.get::<PeopleResponse>(url).await?;
+
+        let person = response.people.pop().ok_or(Error::NoPeople)?;
+
+        Ok(person)
+    }
+
+    /// Get multiple people in a single request
+    pub async fn get_people(&self, person_ids: &[u32]) -> Result<Vec<Person>, Error> {
+        let person_ids = person_ids
+            .iter()
+            .map(u32::to_string)
+            .collect::<Vec<_>>()
+            .join(",");
+
+        let mut modifiers = HashMap::new();
+        modifiers.insert("personIds", person_ids);
+        modifiers.insert("hydrate", String::from("currentTeam"));
+
+        let url = self.get_url("people", Some(modifiers))?;
+
+        let response = self.get::<PeopleResponse>(url).await?;
+
+        Ok(response.people)
+    }
+
+    /// Get stat splits for a person, one entry per stat type and group
+    pub async fn get_person_stats(
+        &self,
+        person_id: u32,
+        query: StatsQuery,
+    ) -> Result<Vec<PersonStats>, Error> {
+        let mut modifiers = HashMap::new();
+        modifiers.insert("stats", String::from(&query.stats));
+        modifiers.insert("group", String::from(&query.group));
+        if let Some(season) = query.season {
+            modifiers.insert("season", season);
+        }
+
+        let url = self.get_url(&format!("people/{}/stats", person_id), Some(modifiers))?;
+
+        let response = self.get::<PersonStatsResponse>(url).await?;
+
+        Ok(response.stats)
+    }
+
     pub async fn get_todays_schedule(&self) -> Result<Schedule, Error> {
         let mut modifiers = HashMap::new();
         modifiers.insert("sportId", String::from(&self.sport));
 
-        let url = self.get_url("schedule", Some(modifiers));
+        let url = self.get_url("schedule", Some(modifiers))?;
 
         let mut response = self.get::<ScheduleResponse>(url).await?;
 
-        let schedule = response
-            .dates
-            .pop()
-            .ok_or_else(|| format_err!("No games for today."))?;
+        let schedule = response.dates.pop().ok_or(Error::NoScheduleDates)?;
 
         Ok(schedule)
     }
@@ -108,20 +366,59 @@ impl Client {
         modifiers.insert("date", date.format("%Y-%m-%d").to_string());
         modifiers.insert("sportId", String::from(&self.sport));
 
-        let url = self.get_url("schedule", Some(modifiers));
+        let url = self.get_url("schedule", Some(modifiers))?;
 
         let mut response = self.get::<ScheduleResponse>(url).await?;
 
-        let schedule = response
-            .dates
-            .pop()
-            .ok_or_else(|| format_err!("No games for today."))?;
+        let schedule = response.dates.pop().ok_or(Error::NoScheduleDates)?;
 
         Ok(schedule)
     }
 
+    /// Get every date matching `query`
+    ///
+    /// Unlike `get_schedule_for`, no dates isn't an error and returns an empty `Vec`.
+    /// Games only carry their season id, see `ScheduleGame::season_of`.
+    pub async fn get_schedule(&self, query: ScheduleQuery) -> Result<Vec<Schedule>, Error> {
+        let mut modifiers = HashMap::new();
+        modifiers.insert("sportId", String::from(&self.sport));
+        if let Some(start_date) = query.start_date {
+            modifiers.insert("startDate", start_date.format("%Y-%m-%d").to_string());
+        }
+        if let Some(end_date) = query.end_date {
+            modifiers.insert("endDate", end_date.format("%Y-%m-%d").to_string());
+        }
+        if let Some(team_id) = query.team_id {
+            modifiers.insert("teamId", team_id.to_string());
+        }
+        if let Some(opponent_id) = query.opponent_id {
+            modifiers.insert("opponentId", opponent_id.to_string());
+        }
+        if let Some(game_type) = query.game_type {
+            modifiers.insert("gameType", game_type);
+        }
+        if let Some(season) = query.season {
+            modifiers.insert("season", season);
+        }
+        if !query.hydrations.is_empty() {
+            let hydrations = query
+                .hydrations
+                .iter()
+                .map(String::from)
+                .collect::<Vec<_>>()
+                .join(",");
+            modifiers.insert("hydrate", hydrations);
+        }
+
+        let url = self.get_url("schedule", Some(modifiers))?;
+
+        let response = self.get::<ScheduleResponse>(url).await?;
+
+        Ok(response.dates)
+    }
+
     pub async fn get_game_content(&self, game_pk: u64) -> Result<GameContentResponse, Error> {
-        let url = self.get_url(&format!("game/{}/content", game_pk), None);
+        let url = self.get_url(&format!("game/{}/content", game_pk), None)?;
 
         let response = self.get::<GameContentResponse>(url).await?;
 
@@ -129,38 +426,376 @@ impl Client {
     }
 
     pub async fn get_game_linescore(&self, game_pk: u64) -> Result<GameLinescoreResponse, Error> {
-        let url = self.get_url(&format!("game/{}/linescore", game_pk), None);
+        let url = self.get_url(&format!("game/{}/linescore", game_pk), None)?;
 
         let response = self.get::<GameLinescoreResponse>(url).await?;
 
         Ok(response)
     }
+
+    pub async fn get_game_boxscore(&self, game_pk: u64) -> Result<GameBoxscoreResponse, Error> {
+        let url = self.get_url(&format!("game/{}/boxscore", game_pk), None)?;
+
+        let response = self.get::<GameBoxscoreResponse>(url).await?;
+
+        Ok(response)
+    }
+
+    /// Get standings for the American and National leagues
+    ///
+    /// Defaults to the current season when `season`, such as "2019", isn't provided
+    pub async fn get_standings(
+        &self,
+        season: Option<&str>,
+        standings_type: StandingsType,
+    ) -> Result<Vec<StandingsRecord>, Error> {
+        let mut modifiers = HashMap::new();
+        modifiers.insert("leagueId", String::from("103,104"));
+        modifiers.insert("standingsTypes", String::from(&standings_type));
+        if let Some(season) = season {
+            modifiers.insert("season", season.to_owned());
+        }
+
+        let url = self.get_url("standings", Some(modifiers))?;
+
+        let response = self.get::<StandingsResponse>(url).await?;
+
+        Ok(response.records)
+    }
+
+    /// Get the top `limit` people in a stat category
+    ///
+    /// Defaults to the current season when `season`, such as "2019", isn't provided
+    pub async fn get_leaders(
+        &self,
+        category: LeaderCategory,
+        season: Option<&str>,
+        limit: u16,
+    ) -> Result<Vec<Leader>, Error> {
+        let mut modifiers = HashMap::new();
+        modifiers.insert("leaderCategories", String::from(&category));
+        modifiers.insert("statGroup", String::from(category.stat_group()));
+        modifiers.insert("sportId", String::from(&self.sport));
+        modifiers.insert("limit", limit.to_string());
+        if let Some(season) = season {
+            modifiers.insert("season", season.to_owned());
+        }
+
+        let url = self.get_url("stats/leaders", Some(modifiers))?;
+
+        let response = self.get::<LeagueLeadersResponse>(url).await?;
+
+        let leaders = response
+            .league_leaders
+            .into_iter()
+            .next()
+            .map(|l| l.leaders)
+            .unwrap_or_default();
+
+        Ok(leaders)
+    }
+
+    pub async fn get_game_feed(&self, game_pk: u64) -> Result<GameFeedResponse, Error> {
+        let url = self.get_feed_url(&format!("game/{}/feed/live", game_pk), None)?;
+
+        let response = self.get::<GameFeedResponse>(url).await?;
+
+        Ok(response)
+    }
+
+    /// Get the live feed of a game in a form that can be refreshed with
+    /// `update_game_feed`
+    pub async fn get_live_game_feed(&self, game_pk: u64) -> Result<LiveGameFeed, Error> {
+        let url = self.get_feed_url(&format!("game/{}/feed/live", game_pk), None)?;
+
+        let raw = self.get::<serde_json::Value>(url).await?;
+
+        live_game_feed(raw)
+    }
+
+    /// Refresh a live feed with only what changed since its timecode
+    ///
+    /// Falls back to downloading the full feed when the diff can't be fetched
+    /// or applied
+    pub async fn update_game_feed(&self, live_feed: &mut LiveGameFeed) -> Result<(), Error> {
+        if let Some(updated) = self.diff_game_feed(live_feed).await {
+            *live_feed = updated;
+            return Ok(());
+        }
+
+        *live_feed = self.get_live_game_feed(live_feed.feed.game_pk).await?;
+
+        Ok(())
+    }
+
+    async fn diff_game_feed(&self, live_feed: &LiveGameFeed) -> Option<LiveGameFeed> {
+        let game_pk = live_feed.feed.game_pk;
+        let time_stamp = live_feed.time_stamp()?;
+        let mut modifiers = HashMap::new();
+        modifiers.insert("startTimecode", time_stamp.to_owned());
+
+        let url = self
+            .get_feed_url(
+                &format!("game/{}/feed/live/diffPatch", game_pk),
+                Some(modifiers),
+            )
+            .ok()?;
+
+        let response = self.get::<serde_json::Value>(url).await.ok()?;
+
+        // The full feed is sent instead of diffs when the timecode is too old
+        let raw = match response {
+            serde_json::Value::Array(_) => {
+                let diffs = serde_json::from_value::<Vec<GameFeedDiff>>(response).ok()?;
+
+                let mut raw = live_feed.raw.clone();
+                for diff in diffs {
+                    json_patch::patch(&mut raw, &diff.diff).ok()?;
+                }
+                raw
+            }
+            full => full,
+        };
+
+        live_game_feed(raw).ok()
+    }
+
+    /// Poll the live feed of a game and stream what changed between polls
+    ///
+    /// The first item is the current status, and later polls only download what
+    /// changed, see `update_game_feed`. Polls wait at least `interval`, or
+    /// longer when the feed asks for it, and the stream ends once the game is
+    /// over, see `GameStatus::is_over`. Failed polls yield the error and polling
+    /// continues, so drop the stream to stop watching.
+    pub fn watch_game(
+        &self,
+        game_pk: u64,
+        interval: Duration,
+    ) -> impl Stream<Item = Result<GameEvent, Error>> + '_ {
+        let state = WatchState {
+            live_feed: None,
+            pending: VecDeque::new(),
+            delay: None,
+            done: false,
+        };
+
+        stream::unfold(state, move |mut state| async move {
+            loop {
+                if let Some(event) = state.pending.pop_front() {
+                    return Some((Ok(event), state));
+                }
+                if state.done {
+                    return None;
+                }
+                if let Some(delay) = state.delay.take() {
+                    Delay::new(delay).await;
+                }
+
+                let result = match state.live_feed.as_mut() {
+                    Some(live_feed) => {
+                        let previous = live_feed.feed().clone();
+                        self.update_game_feed(live_feed)
+                            .await
+                            .map(|_| Some(previous))
+                    }
+                    None => self.get_live_game_feed(game_pk).await.map(|live_feed| {
+                        state.live_feed = Some(live_feed);
+                        None
+                    }),
+                };
+                let previous = match result {
+                    Ok(previous) => previous,
+                    Err(e) => {
+                        state.delay = Some(interval);
+                        return Some((Err(e), state));
+                    }
+                };
+                let feed = state.live_feed.as_ref()?.feed();
+
+                let wait = feed
+                    .meta_data
+                    .as_ref()
+                    .map(|meta_data| Duration::from_secs(meta_data.wait.into()))
+                    .unwrap_or_default();
+                state.delay = Some(interval.max(wait));
+
+                match previous {
+                    Some(previous) => state.pending.extend(feed.events_since(&previous)),
+                    None => state
+                        .pending
+                        .push_back(GameEvent::Status(feed.game_data.status.clone())),
+                }
+                state.done = feed.game_data.status.is_over();
+            }
+        })
+    }
+}
+
+struct WatchState {
+    live_feed: Option<LiveGameFeed>,
+    pending: VecDeque<GameEvent>,
+    delay: Option<Duration>,
+    done: bool,
+}
+
+fn url_with_params(
+    base: &str,
+    path: &str,
+    params: Option<HashMap<&str, String>>,
+) -> Result<Uri, Error> {
+    let uri = match params {
+        Some(params) => {
+            let params = serde_urlencoded::to_string(params).unwrap_or_else(|_| String::from(""));
+            format!("{}/{}?{}", base, path, params)
+        }
+        None => format!("{}/{}", base, path),
+    };
+
+    uri.parse::<Uri>().map_err(|_| Error::InvalidUrl(uri))
+}
+
+fn live_game_feed(raw: serde_json::Value) -> Result<LiveGameFeed, Error> {
+    let feed = serde_path_to_error::deserialize(&raw)?;
+
+    Ok(LiveGameFeed { feed, raw })
 }
 
 impl Default for Client {
     /// Returns client for Sport::Mlb
     fn default() -> Self {
-        let client = HttpClient::builder()
-            .max_connections_per_host(6)
-            .build()
-            .unwrap();
-        let sport = Sport::Mlb;
+        ClientBuilder::default().build().unwrap()
+    }
+}
+
+/// Builder for a [`Client`] with custom base URL and HTTP settings
+///
+/// Any setting not provided falls back to the same value used by
+/// `Client::default()`.
+pub struct ClientBuilder {
+    base: String,
+    sport: Sport,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    user_agent: Option<String>,
+    proxy: Option<Uri>,
+    max_connections: Option<usize>,
+    max_connections_per_host: usize,
+}
 
+impl ClientBuilder {
+    pub fn new() -> Self {
+        ClientBuilder::default()
+    }
+
+    /// Base URL requests are made against, such as `https://statsapi.mlb.com/api/v1`
+    ///
+    /// A trailing `/v1` is swapped for `/v1.1` when requesting the live game feed.
+    pub fn base_url<S: Into<String>>(mut self, base: S) -> Self {
+        self.base = base.into().trim_end_matches('/').to_owned();
+        self
+    }
+
+    /// Sport level whose teams, leagues and schedules are requested, defaults
+    /// to `Sport::Mlb`
+    pub fn sport(mut self, sport: Sport) -> Self {
+        self.sport = sport;
+        self
+    }
+
+    /// Timeout for the entire request, including reading the response body
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    pub fn user_agent<S: Into<String>>(mut self, user_agent: S) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    pub fn proxy(mut self, proxy: Uri) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Maximum number of open connections across all hosts
+    pub fn max_connections(mut self, max: usize) -> Self {
+        self.max_connections = Some(max);
+        self
+    }
+
+    pub fn max_connections_per_host(mut self, max: usize) -> Self {
+        self.max_connections_per_host = max;
+        self
+    }
+
+    /// Fails with `Error::InvalidUrl` unless the base URL is absolute, such as
+    /// `https://host/api/v1`
+    pub fn build(self) -> Result<Client, Error> {
+        let is_absolute = matches!(
+            self.base.parse::<Uri>(),
+            Ok(base) if base.scheme().is_some() && base.authority().is_some()
+        );
+        if !is_absolute {
+            return Err(Error::InvalidUrl(self.base));
+        }
+
+        let mut builder =
+            HttpClient::builder().max_connections_per_host(self.max_connections_per_host);
+
+        if let Some(max) = self.max_connections {
+            builder = builder.max_connections(max);
+        }
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        if let Some(user_agent) = self.user_agent {
+            builder = builder.default_header(http::header::USER_AGENT, user_agent);
+        }
+        if let Some(proxy) = self.proxy {
+            builder = builder.proxy(Some(proxy));
+        }
+
+        let client = builder.build()?;
+
+        Ok(Client {
+            client,
+            base: self.base,
+            sport: self.sport,
+        })
+    }
+}
+
+impl Default for ClientBuilder {
+    fn default() -> Self {
         #[cfg(not(test))]
         let base = String::from("https://statsapi.mlb.com/api/v1");
 
         #[cfg(test)]
         let base = mockito::server_url();
 
-        Client {
-            client,
+        ClientBuilder {
             base,
-            sport,
+            sport: Sport::Mlb,
+            timeout: None,
+            connect_timeout: None,
+            user_agent: None,
+            proxy: None,
+            max_connections: None,
+            max_connections_per_host: 6,
         }
     }
 }
 
-#[derive(Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Sport {
     Mlb,
     Aaa,
@@ -185,6 +820,56 @@ pub enum Sport {
     Hsb,
 }
 
+impl Sport {
+    /// Rank in the affiliate hierarchy, from 0 for MLB down to 7 for Rookie
+    ///
+    /// Sport ids don't follow the hierarchy, Rookie Advanced is 5442 while
+    /// Rookie is 16. Sports outside it, such as winter or independent
+    /// leagues, rank last.
+    pub fn level(&self) -> u8 {
+        match self {
+            Sport::Mlb => 0,
+            Sport::Aaa => 1,
+            Sport::Aax => 2,
+            Sport::Afa => 3,
+            Sport::Afx => 4,
+            Sport::Asx => 5,
+            Sport::Roa => 6,
+            Sport::Rok => 7,
+            _ => u8::MAX,
+        }
+    }
+
+    /// Sport level for a `sportId`, such as the `id` of a team's `sport`
+    pub fn from_id(id: u32) -> Option<Sport> {
+        let sport = match id {
+            1 => Sport::Mlb,
+            11 => Sport::Aaa,
+            12 => Sport::Aax,
+            13 => Sport::Afa,
+            14 => Sport::Afx,
+            15 => Sport::Asx,
+            5442 => Sport::Roa,
+            16 => Sport::Rok,
+            17 => Sport::Win,
+            8 => Sport::Bbl,
+            21 => Sport::Min,
+            23 => Sport::Ind,
+            31 => Sport::Jml,
+            51 => Sport::Int,
+            508 => Sport::Nat,
+            509 => Sport::Nae,
+            600 => Sport::Nav,
+            510 => Sport::Nas,
+            512 => Sport::Naw,
+            22 => Sport::Bbc,
+            586 => Sport::Hsb,
+            _ => return None,
+        };
+        Some(sport)
+    }
+}
+
 impl From<&Sport> for String {
     fn from(sport: &Sport) -> String {
         let s = match sport {
@@ -1,14 +1,27 @@
-use crate::model::nhl::{
-    GameContentResponse, GameLinescoreResponse, Schedule, ScheduleResponse, Team, TeamsResponse,
+use crate::{
+    model::nhl::{
+        Conference, ConferenceDetail, ConferencesResponse, Division, DivisionDetail,
+        DivisionsResponse, GameBoxscoreResponse, GameContentResponse, GameEvent, GameFeedDiff,
+        GameFeedResponse, GameLinescoreResponse, Leader, LeaderCategory, LeagueLeadersResponse,
+        LiveGameFeed, PeopleResponse, Person, PersonStats, PersonStatsResponse, RosterEntry,
+        RosterResponse, Schedule, ScheduleQuery, ScheduleResponse, Season, SeasonsResponse,
+        StandingsRecord, StandingsResponse, StandingsType, StatsQuery, Team, TeamStats,
+        TeamStatsResponse, TeamsResponse, Venue, VenuesResponse,
+    },
+    Error,
 };
-use failure::{format_err, Error, ResultExt};
-use futures::AsyncReadExt;
+use futures::{future, stream, AsyncReadExt, Stream};
+use futures_timer::Delay;
 use isahc::{
+    config::Configurable,
     http::{self, Uri},
-    AsyncBody, HttpClient, Request,
+    AsyncBody, AsyncReadResponseExt, HttpClient, Request,
 };
 use serde::de::DeserializeOwned;
-use std::collections::HashMap;
+use std::{
+    collections::{BTreeMap, HashMap, VecDeque},
+    time::Duration,
+};
 
 pub struct Client {
     client: HttpClient,
@@ -20,15 +33,12 @@ impl Client {
         Client::default()
     }
 
-    fn get_url(&self, path: &str, params: Option<HashMap<&str, String>>) -> http::Uri {
-        if let Some(params) = params {
-            let params = serde_urlencoded::to_string(params).unwrap_or_else(|_| String::from(""));
-            let uri = format!("{}/{}?{}", self.base, path, params);
-            uri.parse::<Uri>().unwrap()
-        } else {
-            let uri = format!("{}/{}", self.base, path);
-            uri.parse::<Uri>().unwrap()
-        }
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::default()
+    }
+
+    fn get_url(&self, path: &str, params: Option<HashMap<&str, String>>) -> Result<Uri, Error> {
+        url_with_params(&self.base, path, params)
     }
 
     async fn get<T: DeserializeOwned>(&self, url: Uri) -> Result<T, Error> {
@@ -38,51 +48,248 @@ impl Client {
             .body(AsyncBody::empty())
             .unwrap();
 
-        let res = self
-            .client
-            .send_async(request)
-            .await
-            .context("Failed to get request")?;
+        let mut res = self.client.send_async(request).await?;
+
+        if !res.status().is_success() {
+            let code = res.status().as_u16();
+            let body = res.text().await.unwrap_or_default();
+            return Err(Error::from_status(code, body));
+        }
 
         let mut body = res.into_body();
         let mut bytes = Vec::new();
         body.read_to_end(&mut bytes).await?;
 
-        let response = serde_json::from_slice(&bytes)?;
+        let de = &mut serde_json::Deserializer::from_slice(&bytes);
+        let response = serde_path_to_error::deserialize(de)?;
 
         Ok(response)
     }
 
     pub async fn get_teams(&self) -> Result<Vec<Team>, Error> {
-        let url = self.get_url("teams", None);
+        let url = self.get_url("teams", None)?;
 
         let response = self.get::<TeamsResponse>(url).await?;
 
         Ok(response.teams)
     }
 
+    /// Get teams grouped by division, ordered by division id
+    ///
+    /// Teams without a division yet, such as an expansion team, are left out
+    pub async fn teams_by_division(&self) -> Result<Vec<(DivisionDetail, Vec<Team>)>, Error> {
+        let teams = self.get_teams().await?;
+
+        Ok(group_teams(teams, |team| {
+            let division = team.division.as_ref()?;
+            Some((division.id?, division.clone()))
+        }))
+    }
+
+    /// Get teams grouped by conference, ordered by conference id
+    ///
+    /// Teams without a conference yet, such as an expansion team, are left out
+    pub async fn teams_by_conference(&self) -> Result<Vec<(ConferenceDetail, Vec<Team>)>, Error> {
+        let teams = self.get_teams().await?;
+
+        Ok(group_teams(teams, |team| {
+            let conference = team.conference.as_ref()?;
+            Some((conference.id?, conference.clone()))
+        }))
+    }
+
     pub async fn get_team(&self, team_id: u32) -> Result<Team, Error> {
-        let url = self.get_url(&format!("teams/{}", team_id), None);
+        let url = self.get_url(&format!("teams/{}", team_id), None)?;
+
+        let mut response = self.get::<TeamsResponse>(url).await?;
+
+        let team = response.teams.pop().ok_or(Error::NoTeams)?;
+
+        Ok(team)
+    }
+
+    pub async fn get_divisions(&self) -> Result<Vec<Division>, Error> {
+        let url = self.get_url("divisions", None)?;
+
+        let response = self.get::<DivisionsResponse>(url).await?;
+
+        Ok(response.divisions)
+    }
+
+    /// Get a division by the `id` found on teams and standings records
+    pub async fn get_division(&self, division_id: u32) -> Result<Division, Error> {
+        let url = self.get_url(&format!("divisions/{}", division_id), None)?;
+
+        let mut response = self.get::<DivisionsResponse>(url).await?;
+
+        let division = response.divisions.pop().ok_or(Error::NoDivisions)?;
+
+        Ok(division)
+    }
+
+    pub async fn get_conferences(&self) -> Result<Vec<Conference>, Error> {
+        let url = self.get_url("conferences", None)?;
+
+        let response = self.get::<ConferencesResponse>(url).await?;
+
+        Ok(response.conferences)
+    }
+
+    /// Get a conference by the `id` found on teams and standings records
+    pub async fn get_conference(&self, conference_id: u32) -> Result<Conference, Error> {
+        let url = self.get_url(&format!("conferences/{}", conference_id), None)?;
+
+        let mut response = self.get::<ConferencesResponse>(url).await?;
+
+        let conference = response.conferences.pop().ok_or(Error::NoConferences)?;
+
+        Ok(conference)
+    }
+
+    pub async fn get_venues(&self) -> Result<Vec<Venue>, Error> {
+        let url = self.get_url("venues", None)?;
+
+        let response = self.get::<VenuesResponse>(url).await?;
+
+        Ok(response.venues)
+    }
+
+    pub async fn get_venue(&self, venue_id: u32) -> Result<Venue, Error> {
+        let url = self.get_url(&format!("venues/{}", venue_id), None)?;
+
+        let mut response = self.get::<VenuesResponse>(url).await?;
+
+        let venue = response.venues.pop().ok_or(Error::NoVenues)?;
+
+        Ok(venue)
+    }
+
+    pub async fn get_seasons(&self) -> Result<Vec<Season>, Error> {
+        let url = self.get_url("seasons", None)?;
+
+        let response = self.get::<SeasonsResponse>(url).await?;
+
+        Ok(response.seasons)
+    }
+
+    pub async fn get_current_season(&self) -> Result<Season, Error> {
+        let url = self.get_url("seasons/current", None)?;
+
+        let mut response = self.get::<SeasonsResponse>(url).await?;
+
+        let season = response.seasons.pop().ok_or(Error::NoSeasons)?;
+
+        Ok(season)
+    }
+
+    /// Get the season `date` belongs to, see [`Season::for_date`]
+    ///
+    /// Fetches every season on each call, when resolving many dates call
+    /// `get_seasons` once and use `Season::for_date` instead.
+    pub async fn season_for_date(&self, date: chrono::NaiveDate) -> Result<Option<Season>, Error> {
+        let seasons = self.get_seasons().await?;
+
+        Ok(Season::for_date(&seasons, date).cloned())
+    }
+
+    /// Get team with `roster` populated
+    pub async fn get_team_with_roster(&self, team_id: u32) -> Result<Team, Error> {
+        let mut modifiers = HashMap::new();
+        modifiers.insert("expand", String::from("team.roster"));
+
+        let url = self.get_url(&format!("teams/{}", team_id), Some(modifiers))?;
 
         let mut response = self.get::<TeamsResponse>(url).await?;
 
-        let team = response
-            .teams
-            .pop()
-            .ok_or_else(|| format_err!("Failed to get team response."))?;
+        let team = response.teams.pop().ok_or(Error::NoTeams)?;
 
         Ok(team)
     }
 
+    /// Get season totals for a team along with their league ranks
+    ///
+    /// Defaults to the current season when `season`, such as "20192020", isn't provided
+    pub async fn get_team_stats(
+        &self,
+        team_id: u32,
+        season: Option<&str>,
+    ) -> Result<Vec<TeamStats>, Error> {
+        let params = season.map(|season| {
+            let mut modifiers = HashMap::new();
+            modifiers.insert("season", season.to_owned());
+            modifiers
+        });
+
+        let url = self.get_url(&format!("teams/{}/stats", team_id), params)?;
+
+        let response = self.get::<TeamStatsResponse>(url).await?;
+
+        Ok(response.stats)
+    }
+
+    /// Defaults to the current season when `season`, such as "20192020", isn't provided
+    pub async fn get_team_roster(
+        &self,
+        team_id: u32,
+        season: Option<&str>,
+    ) -> Result<Vec<RosterEntry>, Error> {
+        let params = season.map(|season| {
+            let mut modifiers = HashMap::new();
+            modifiers.insert("season", season.to_owned());
+            modifiers
+        });
+
+        let url = self.get_url(&format!("teams/{}/roster", team_id), params)?;
+
+        let response = self.get::<RosterResponse>(url).await?;
+
+        Ok(response.roster)
+    }
+
+    pub async fn get_person(&self, person_id: u32) -> Result<Person, Error> {
+        let url = self.get_url(&format!("people/{}", person_id), None)?;
+
+        let mut response = self.get::<PeopleResponse>(url).await?;
+
+        let person = response.people.pop().ok_or(Error::NoPeople)?;
+
+        Ok(person)
+    }
+
+    /// Get multiple people
+    ///
+    /// The NHL API has no batch lookup, so people are requested concurrently
+    pub async fn get_people(&self, person_ids: &[u32]) -> Result<Vec<Person>, Error> {
+        let requests = person_ids.iter().map(|id| self.get_person(*id));
+
+        future::try_join_all(requests).await
+    }
+
+    /// Get stat splits for a person, one entry per stat type
+    pub async fn get_person_stats(
+        &self,
+        person_id: u32,
+        query: StatsQuery,
+    ) -> Result<Vec<PersonStats>, Error> {
+        let mut modifiers = HashMap::new();
+        modifiers.insert("stats", String::from(&query.stats));
+        if let Some(season) = query.season {
+            modifiers.insert("season", season);
+        }
+
+        let url = self.get_url(&format!("people/{}/stats", person_id), Some(modifiers))?;
+
+        let response = self.get::<PersonStatsResponse>(url).await?;
+
+        Ok(response.stats)
+    }
+
     pub async fn get_todays_schedule(&self) -> Result<Schedule, Error> {
-        let url = self.get_url("schedule", None);
+        let url = self.get_url("schedule", None)?;
 
         let mut response = self.get::<ScheduleResponse>(url).await?;
 
-        let schedule = response
-            .dates
-            .pop()
-            .ok_or_else(|| format_err!("Failed to get team response."))?;
+        let schedule = response.dates.pop().ok_or(Error::NoScheduleDates)?;
 
         Ok(schedule)
     }
@@ -91,20 +298,58 @@ impl Client {
         let mut modifiers = HashMap::new();
         modifiers.insert("date", date.format("%Y-%m-%d").to_string());
 
-        let url = self.get_url("schedule", Some(modifiers));
+        let url = self.get_url("schedule", Some(modifiers))?;
 
         let mut response = self.get::<ScheduleResponse>(url).await?;
 
-        let schedule = response
-            .dates
-            .pop()
-            .ok_or_else(|| format_err!("No games for today."))?;
+        let schedule = response.dates.pop().ok_or(Error::NoScheduleDates)?;
 
         Ok(schedule)
     }
 
+    /// Get every date matching `query`
+    ///
+    /// Unlike `get_schedule_for`, no dates isn't an error and returns an empty `Vec`.
+    /// Games only carry their season id, see `ScheduleGame::season_of`.
+    pub async fn get_schedule(&self, query: ScheduleQuery) -> Result<Vec<Schedule>, Error> {
+        let mut modifiers = HashMap::new();
+        if let Some(start_date) = query.start_date {
+            modifiers.insert("startDate", start_date.format("%Y-%m-%d").to_string());
+        }
+        if let Some(end_date) = query.end_date {
+            modifiers.insert("endDate", end_date.format("%Y-%m-%d").to_string());
+        }
+        if let Some(team_id) = query.team_id {
+            modifiers.insert("teamId", team_id.to_string());
+        }
+        if let Some(opponent_id) = query.opponent_id {
+            modifiers.insert("opponentId", opponent_id.to_string());
+        }
+        if let Some(game_type) = query.game_type {
+            modifiers.insert("gameType", game_type);
+        }
+        if let Some(season) = query.season {
+            modifiers.insert("season", season);
+        }
+        if !query.expands.is_empty() {
+            let expands = query
+                .expands
+                .iter()
+                .map(String::from)
+                .collect::<Vec<_>>()
+                .join(",");
+            modifiers.insert("expand", expands);
+        }
+
+        let url = self.get_url("schedule", Some(modifiers))?;
+
+        let response = self.get::<ScheduleResponse>(url).await?;
+
+        Ok(response.dates)
+    }
+
     pub async fn get_game_content(&self, game_pk: u64) -> Result<GameContentResponse, Error> {
-        let url = self.get_url(&format!("game/{}/content", game_pk), None);
+        let url = self.get_url(&format!("game/{}/content", game_pk), None)?;
 
         let response = self.get::<GameContentResponse>(url).await?;
 
@@ -112,27 +357,373 @@ impl Client {
     }
 
     pub async fn get_game_linescore(&self, game_pk: u64) -> Result<GameLinescoreResponse, Error> {
-        let url = self.get_url(&format!("game/{}/linescore", game_pk), None);
+        let url = self.get_url(&format!("game/{}/linescore", game_pk), None)?;
 
         let response = self.get::<GameLinescoreResponse>(url).await?;
 
         Ok(response)
     }
+
+    pub async fn get_game_boxscore(&self, game_pk: u64) -> Result<GameBoxscoreResponse, Error> {
+        let url = self.get_url(&format!("game/{}/boxscore", game_pk), None)?;
+
+        let response = self.get::<GameBoxscoreResponse>(url).await?;
+
+        Ok(response)
+    }
+
+    /// Defaults to the current season when `season`, such as "20192020", isn't provided
+    pub async fn get_standings(
+        &self,
+        season: Option<&str>,
+        standings_type: StandingsType,
+    ) -> Result<Vec<StandingsRecord>, Error> {
+        let mut modifiers = HashMap::new();
+        modifiers.insert("standingsType", String::from(&standings_type));
+        if let Some(season) = season {
+            modifiers.insert("season", season.to_owned());
+        }
+
+        let url = self.get_url("standings", Some(modifiers))?;
+
+        let response = self.get::<StandingsResponse>(url).await?;
+
+        Ok(response.records)
+    }
+
+    /// Get the top `limit` people in a stat category
+    ///
+    /// Defaults to the current season when `season`, such as "20192020", isn't provided
+    pub async fn get_leaders(
+        &self,
+        category: LeaderCategory,
+        season: Option<&str>,
+        limit: u16,
+    ) -> Result<Vec<Leader>, Error> {
+        let mut modifiers = HashMap::new();
+        modifiers.insert("leaderCategories", String::from(&category));
+        modifiers.insert("limit", limit.to_string());
+        if let Some(season) = season {
+            modifiers.insert("season", season.to_owned());
+        }
+
+        let url = self.get_url("stats/leaders", Some(modifiers))?;
+
+        let response = self.get::<LeagueLeadersResponse>(url).await?;
+
+        let leaders = response
+            .league_leaders
+            .into_iter()
+            .next()
+            .map(|l| l.leaders)
+            .unwrap_or_default();
+
+        Ok(leaders)
+    }
+
+    pub async fn get_game_feed(&self, game_pk: u64) -> Result<GameFeedResponse, Error> {
+        let url = self.get_url(&format!("game/{}/feed/live", game_pk), None)?;
+
+        let response = self.get::<GameFeedResponse>(url).await?;
+
+        Ok(response)
+    }
+
+    /// Get the live feed of a game in a form that can be refreshed with
+    /// `update_game_feed`
+    pub async fn get_live_game_feed(&self, game_pk: u64) -> Result<LiveGameFeed, Error> {
+        let url = self.get_url(&format!("game/{}/feed/live", game_pk), None)?;
+
+        let raw = self.get::<serde_json::Value>(url).await?;
+
+        live_game_feed(raw)
+    }
+
+    /// Refresh a live feed with only what changed since its timecode
+    ///
+    /// Falls back to downloading the full feed when the diff can't be fetched
+    /// or applied
+    pub async fn update_game_feed(&self, live_feed: &mut LiveGameFeed) -> Result<(), Error> {
+        if let Some(updated) = self.diff_game_feed(live_feed).await {
+            *live_feed = updated;
+            return Ok(());
+        }
+
+        *live_feed = self.get_live_game_feed(live_feed.feed.game_pk).await?;
+
+        Ok(())
+    }
+
+    async fn diff_game_feed(&self, live_feed: &LiveGameFeed) -> Option<LiveGameFeed> {
+        let game_pk = live_feed.feed.game_pk;
+        let time_stamp = live_feed.time_stamp()?;
+        let url = {
+            let mut modifiers = HashMap::new();
+            modifiers.insert("startTimecode", time_stamp.to_owned());
+            self.get_url(
+                &format!("game/{}/feed/live/diffPatch", game_pk),
+                Some(modifiers),
+            )
+            .ok()?
+        };
+
+        let response = self.get::<serde_json::Value>(url).await.ok()?;
+
+        // The full feed is sent instead of diffs when the timecode is too old
+        let raw = match response {
+            serde_json::Value::Array(_) => {
+                let diffs = serde_json::from_value::<Vec<GameFeedDiff>>(response).ok()?;
+
+                let mut raw = live_feed.raw.clone();
+                for diff in diffs {
+                    json_patch::patch(&mut raw, &diff.diff).ok()?;
+                }
+                raw
+            }
+            full => full,
+        };
+
+        live_game_feed(raw).ok()
+    }
+
+    /// Poll the live feed of a game and stream what changed between polls
+    ///
+    /// The first item is the current status, and later polls only download what
+    /// changed, see `update_game_feed`. Polls wait at least `interval`, or
+    /// longer when the feed asks for it, and the stream ends once the game is
+    /// over, see `GameStatus::is_over`. Failed polls yield the error and polling
+    /// continues, so drop the stream to stop watching.
+    pub fn watch_game(
+        &self,
+        game_pk: u64,
+        interval: Duration,
+    ) -> impl Stream<Item = Result<GameEvent, Error>> + '_ {
+        let state = WatchState {
+            live_feed: None,
+            pending: VecDeque::new(),
+            delay: None,
+            done: false,
+        };
+
+        stream::unfold(state, move |mut state| async move {
+            loop {
+                if let Some(event) = state.pending.pop_front() {
+                    return Some((Ok(event), state));
+                }
+                if state.done {
+                    return None;
+                }
+                if let Some(delay) = state.delay.take() {
+                    Delay::new(delay).await;
+                }
+
+                let result = match state.live_feed.as_mut() {
+                    Some(live_feed) => {
+                        let previous = live_feed.feed().clone();
+                        self.update_game_feed(live_feed)
+                            .await
+                            .map(|_| Some(previous))
+                    }
+                    None => self.get_live_game_feed(game_pk).await.map(|live_feed| {
+                        state.live_feed = Some(live_feed);
+                        None
+                    }),
+                };
+                let previous = match result {
+                    Ok(previous) => previous,
+                    Err(e) => {
+                        state.delay = Some(interval);
+                        return Some((Err(e), state));
+                    }
+                };
+                let feed = state.live_feed.as_ref()?.feed();
+
+                let wait = feed
+                    .meta_data
+                    .as_ref()
+                    .map(|meta_data| Duration::from_secs(meta_data.wait.into()))
+                    .unwrap_or_default();
+                state.delay = Some(interval.max(wait));
+
+                match previous {
+                    Some(previous) => state.pending.extend(feed.events_since(&previous)),
+                    None => state
+                        .pending
+                        .push_back(GameEvent::Status(feed.game_data.status.clone())),
+                }
+                state.done = feed.game_data.status.is_over();
+            }
+        })
+    }
+}
+
+struct WatchState {
+    live_feed: Option<LiveGameFeed>,
+    pending: VecDeque<GameEvent>,
+    delay: Option<Duration>,
+    done: bool,
+}
+
+/// Groups teams by the id and detail returned by `group`, ordered by id
+fn group_teams<K, F>(teams: Vec<Team>, group: F) -> Vec<(K, Vec<Team>)>
+where
+    F: Fn(&Team) -> Option<(u32, K)>,
+{
+    let mut groups: BTreeMap<u32, (K, Vec<Team>)> = BTreeMap::new();
+
+    for team in teams {
+        if let Some((id, detail)) = group(&team) {
+            groups
+                .entry(id)
+                .or_insert_with(|| (detail, vec![]))
+                .1
+                .push(team);
+        }
+    }
+
+    groups.into_values().collect()
+}
+
+fn url_with_params(
+    base: &str,
+    path: &str,
+    params: Option<HashMap<&str, String>>,
+) -> Result<Uri, Error> {
+    let uri = match params {
+        Some(params) => {
+            let params = serde_urlencoded::to_string(params).unwrap_or_else(|_| String::from(""));
+            format!("{}/{}?{}", base, path, params)
+        }
+        None => format!("{}/{}", base, path),
+    };
+
+    uri.parse::<Uri>().map_err(|_| Error::InvalidUrl(uri))
+}
+
+fn live_game_feed(raw: serde_json::Value) -> Result<LiveGameFeed, Error> {
+    let feed = serde_path_to_error::deserialize(&raw)?;
+
+    Ok(LiveGameFeed { feed, raw })
 }
 
 impl Default for Client {
     fn default() -> Self {
-        let client = HttpClient::builder()
-            .max_connections_per_host(6)
-            .build()
-            .unwrap();
+        ClientBuilder::default().build().unwrap()
+    }
+}
+
+/// Builder for a [`Client`] with custom base URL and HTTP settings
+///
+/// Any setting not provided falls back to the same value used by
+/// `Client::default()`.
+pub struct ClientBuilder {
+    base: String,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    user_agent: Option<String>,
+    proxy: Option<Uri>,
+    max_connections: Option<usize>,
+    max_connections_per_host: usize,
+}
+
+impl ClientBuilder {
+    pub fn new() -> Self {
+        ClientBuilder::default()
+    }
+
+    /// Base URL requests are made against, such as `https://statsapi.web.nhl.com/api/v1`
+    pub fn base_url<S: Into<String>>(mut self, base: S) -> Self {
+        self.base = base.into().trim_end_matches('/').to_owned();
+        self
+    }
+
+    /// Timeout for the entire request, including reading the response body
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
 
+    pub fn user_agent<S: Into<String>>(mut self, user_agent: S) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    pub fn proxy(mut self, proxy: Uri) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Maximum number of open connections across all hosts
+    pub fn max_connections(mut self, max: usize) -> Self {
+        self.max_connections = Some(max);
+        self
+    }
+
+    pub fn max_connections_per_host(mut self, max: usize) -> Self {
+        self.max_connections_per_host = max;
+        self
+    }
+
+    /// Fails with `Error::InvalidUrl` unless the base URL is absolute, such as
+    /// `https://host/api/v1`
+    pub fn build(self) -> Result<Client, Error> {
+        let is_absolute = matches!(
+            self.base.parse::<Uri>(),
+            Ok(base) if base.scheme().is_some() && base.authority().is_some()
+        );
+        if !is_absolute {
+            return Err(Error::InvalidUrl(self.base));
+        }
+
+        let mut builder =
+            HttpClient::builder().max_connections_per_host(self.max_connections_per_host);
+
+        if let Some(max) = self.max_connections {
+            builder = builder.max_connections(max);
+        }
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        if let Some(user_agent) = self.user_agent {
+            builder = builder.default_header(http::header::USER_AGENT, user_agent);
+        }
+        if let Some(proxy) = self.proxy {
+            builder = builder.proxy(Some(proxy));
+        }
+
+        let client = builder.build()?;
+
+        Ok(Client {
+            client,
+            base: self.base,
+        })
+    }
+}
+
+impl Default for ClientBuilder {
+    fn default() -> Self {
         #[cfg(not(test))]
         let base = String::from("https://statsapi.web.nhl.com/api/v1");
 
         #[cfg(test)]
         let base = mockito::server_url();
 
-        Client { client, base }
+        ClientBuilder {
+            base,
+            timeout: None,
+            connect_timeout: None,
+            user_agent: None,
+            proxy: None,
+            max_connections: None,
+            max_connections_per_host: 6,
+        }
     }
 }
@@ -0,0 +1,151 @@
+use serde::Deserialize;
+use std::{error, fmt};
+
+/// Error returned by `MlbClient` and `NhlClient`
+#[derive(Debug)]
+pub enum Error {
+    /// Failed to send the request or read the response body
+    Http(isahc::Error),
+    /// Server responded with 404, such as for an unknown `game_pk` or team id
+    NotFound(ApiError),
+    /// Server responded with a 5xx status code
+    Server(ApiError),
+    /// Server responded with any other non-2xx status code
+    Status(ApiError),
+    /// Base URL, or a request URL built from it, isn't a valid absolute URL
+    InvalidUrl(String),
+    /// Response body couldn't be deserialized, `path` points to the failing field
+    Json {
+        path: String,
+        source: serde_json::Error,
+    },
+    /// Schedule response didn't contain any dates
+    NoScheduleDates,
+    /// Teams response didn't contain any teams
+    NoTeams,
+    /// People response didn't contain any people
+    NoPeople,
+    /// Divisions response didn't contain any divisions
+    NoDivisions,
+    /// Conferences response didn't contain any conferences
+    NoConferences,
+    /// Leagues response didn't contain any leagues
+    NoLeagues,
+    /// Venues response didn't contain any venues
+    NoVenues,
+    /// Seasons response didn't contain any seasons
+    NoSeasons,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Http(e) => write!(f, "Failed to get request: {}", e),
+            Error::NotFound(e) => write!(f, "Not found: {}", e),
+            Error::Server(e) => write!(f, "Server error: {}", e),
+            Error::Status(e) => write!(f, "Request failed: {}", e),
+            Error::InvalidUrl(url) => write!(f, "Invalid URL: {}", url),
+            Error::Json { path, source } => {
+                write!(
+                    f,
+                    "Failed to deserialize response at `{}`: {}",
+                    path, source
+                )
+            }
+            Error::NoScheduleDates => write!(f, "No games for today."),
+            Error::NoTeams => write!(f, "Failed to get team response."),
+            Error::NoPeople => write!(f, "Failed to get person response."),
+            Error::NoDivisions => write!(f, "Failed to get division response."),
+            Error::NoConferences => write!(f, "Failed to get conference response."),
+            Error::NoLeagues => write!(f, "Failed to get league response."),
+            Error::NoVenues => write!(f, "Failed to get venue response."),
+            Error::NoSeasons => write!(f, "Failed to get season response."),
+        }
+    }
+}
+
+impl Error {
+    /// Builds the error for a non-2xx response from its status code and body
+    pub(crate) fn from_status(code: u16, body: String) -> Error {
+        let api_error = ApiError::new(code, body);
+
+        match code {
+            404 => Error::NotFound(api_error),
+            500..=599 => Error::Server(api_error),
+            _ => Error::Status(api_error),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Http(e) => Some(e),
+            Error::Json { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<isahc::Error> for Error {
+    fn from(e: isahc::Error) -> Self {
+        Error::Http(e)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Http(e.into())
+    }
+}
+
+impl From<serde_path_to_error::Error<serde_json::Error>> for Error {
+    fn from(e: serde_path_to_error::Error<serde_json::Error>) -> Self {
+        Error::Json {
+            path: e.path().to_string(),
+            source: e.into_inner(),
+        }
+    }
+}
+
+/// Details of a non-2xx response
+///
+/// The stats API usually responds with a payload such as
+/// `{"messageNumber": 10, "message": "Object not found"}`, which is parsed
+/// when present. The raw body is always kept.
+#[derive(Debug, Clone)]
+pub struct ApiError {
+    pub code: u16,
+    pub message_number: Option<u32>,
+    pub message: Option<String>,
+    pub body: String,
+}
+
+impl ApiError {
+    fn new(code: u16, body: String) -> Self {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Payload {
+            message_number: Option<u32>,
+            message: Option<String>,
+        }
+
+        let payload = serde_json::from_str::<Payload>(&body).ok();
+
+        ApiError {
+            code,
+            message_number: payload.as_ref().and_then(|p| p.message_number),
+            message: payload.and_then(|p| p.message),
+            body,
+        }
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.message {
+            Some(message) => write!(f, "status {}, {}", self.code, message),
+            None => write!(f, "status {}", self.code),
+        }
+    }
+}
@@ -1,10 +1,13 @@
 mod client;
+mod error;
+
+pub use error::{ApiError, Error};
 
 #[cfg(feature = "mlb")]
-pub use client::mlb::Client as MlbClient;
+pub use client::mlb::{Client as MlbClient, ClientBuilder as MlbClientBuilder, Sport as MlbSport};
 
 #[cfg(feature = "nhl")]
-pub use client::nhl::Client as NhlClient;
+pub use client::nhl::{Client as NhlClient, ClientBuilder as NhlClientBuilder};
 
 pub mod model;
 
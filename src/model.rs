@@ -1,3 +1,6 @@
+#[cfg(any(feature = "mlb", feature = "nhl"))]
+mod common;
+
 #[cfg(feature = "mlb")]
 pub mod mlb;
 
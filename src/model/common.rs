@@ -0,0 +1,266 @@
+use serde::{
+    de::{value::UnitDeserializer, DeserializeSeed, Error as _, IgnoredAny, MapAccess, Visitor},
+    Deserialize, Serialize,
+};
+use std::marker::PhantomData;
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameStatus {
+    pub abstract_game_state: AbstractGameState,
+    pub detailed_state: DetailedState,
+    /// Code such as "F" for MLB or "7" for NHL
+    #[serde(default)]
+    pub coded_game_state: String,
+    #[serde(default)]
+    pub status_code: String,
+    pub abstract_game_code: Option<String>,
+    /// Cause of a delay or postponement, such as "Rain", only sent by MLB
+    pub reason: Option<String>,
+    #[serde(default, rename = "startTimeTBD")]
+    pub start_time_tbd: bool,
+}
+
+impl GameStatus {
+    pub fn is_preview(&self) -> bool {
+        self.abstract_game_state == AbstractGameState::Preview
+    }
+
+    pub fn is_live(&self) -> bool {
+        self.abstract_game_state == AbstractGameState::Live
+    }
+
+    pub fn is_final(&self) -> bool {
+        self.abstract_game_state == AbstractGameState::Final
+    }
+
+    pub fn is_postponed(&self) -> bool {
+        self.detailed_state == DetailedState::Postponed
+    }
+
+    /// Whether the game is final, postponed or cancelled, so its feed won't
+    /// change anymore
+    pub fn is_over(&self) -> bool {
+        self.is_final()
+            || matches!(
+                self.detailed_state,
+                DetailedState::Postponed | DetailedState::Cancelled
+            )
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+pub enum AbstractGameState {
+    Preview,
+    Live,
+    Final,
+    #[serde(other)]
+    Other,
+}
+
+/// Parsed from `detailedState`, which may carry a cause such as "Delayed: Rain"
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(from = "String", into = "String")]
+pub enum DetailedState {
+    Scheduled,
+    PreGame,
+    Warmup,
+    InProgress,
+    Delayed,
+    Suspended,
+    Postponed,
+    Cancelled,
+    GameOver,
+    Final,
+    Other(String),
+}
+
+impl From<String> for DetailedState {
+    fn from(s: String) -> DetailedState {
+        match s.as_str() {
+            s if s.starts_with("Scheduled") => DetailedState::Scheduled,
+            s if s.starts_with("Pre-Game") => DetailedState::PreGame,
+            s if s.starts_with("Warmup") => DetailedState::Warmup,
+            s if s.starts_with("In Progress") => DetailedState::InProgress,
+            s if s.starts_with("Delayed") => DetailedState::Delayed,
+            s if s.starts_with("Suspended") => DetailedState::Suspended,
+            s if s.starts_with("Postponed") => DetailedState::Postponed,
+            s if s.starts_with("Cancelled") => DetailedState::Cancelled,
+            s if s.starts_with("Game Over") => DetailedState::GameOver,
+            s if s.starts_with("Final") || s.starts_with("Completed Early") => DetailedState::Final,
+            _ => DetailedState::Other(s),
+        }
+    }
+}
+
+impl From<DetailedState> for String {
+    fn from(state: DetailedState) -> String {
+        let s = match state {
+            DetailedState::Scheduled => "Scheduled",
+            DetailedState::PreGame => "Pre-Game",
+            DetailedState::Warmup => "Warmup",
+            DetailedState::InProgress => "In Progress",
+            DetailedState::Delayed => "Delayed",
+            DetailedState::Suspended => "Suspended",
+            DetailedState::Postponed => "Postponed",
+            DetailedState::Cancelled => "Cancelled",
+            DetailedState::GameOver => "Game Over",
+            DetailedState::Final => "Final",
+            DetailedState::Other(s) => return s,
+        };
+        String::from(s)
+    }
+}
+
+pub(crate) fn fail_as_none<'de, D, T>(de: D) -> Result<Option<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: serde::Deserialize<'de>,
+{
+    let result = T::deserialize(de);
+    match result {
+        Ok(t) => Ok(Some(t)),
+        Err(_) => Ok(None),
+    }
+}
+
+/// Parses values the API sends as strings, such as ranks or games back.
+/// Placeholders like "-" or "E" become `None`.
+pub(crate) fn from_str_as_option<'de, D, T>(de: D) -> Result<Option<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: std::str::FromStr,
+{
+    let value = Option::<String>::deserialize(de)?;
+    Ok(value.and_then(|s| s.parse().ok()))
+}
+
+/// Parses ranks the API sends either as numbers or as ordinals such as "7th"
+pub(crate) fn ordinal_as_option<'de, D>(de: D) -> Result<Option<u16>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let value = Option::<serde_json::Value>::deserialize(de)?;
+    Ok(match value {
+        Some(serde_json::Value::Number(n)) => n.as_u64().map(|n| n as u16),
+        Some(serde_json::Value::String(s)) => s.trim_end_matches(char::is_alphabetic).parse().ok(),
+        _ => None,
+    })
+}
+
+/// Stats whose stat line type depends on the `type` display name sent next to
+/// their `splits`, and on the `group` display name for MLB
+pub(crate) trait FromSplits: Sized {
+    /// Whether a `group` is sent and has to be read before `splits`
+    const GROUPED: bool;
+
+    fn from_splits<'de, D>(
+        stats_type: &str,
+        group: Option<&str>,
+        splits: D,
+    ) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>;
+}
+
+struct SplitsSeed<'a, T> {
+    stats_type: &'a str,
+    group: Option<&'a str>,
+    marker: PhantomData<T>,
+}
+
+impl<'de, T: FromSplits> DeserializeSeed<'de> for SplitsSeed<'_, T> {
+    type Value = T;
+
+    fn deserialize<D>(self, de: D) -> Result<T, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        T::from_splits(self.stats_type, self.group, de)
+    }
+}
+
+/// Reads `type` and `group` first so `splits` can be deserialized in place,
+/// which keeps the path to a failing split in `Error::Json`
+pub(crate) struct StatsVisitor<T>(pub(crate) PhantomData<T>);
+
+impl<'de, T: FromSplits> Visitor<'de> for StatsVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("stats with a type and splits")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<T, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut stats_type: Option<String> = None;
+        let mut group: Option<String> = None;
+        let mut stats = None;
+        // Only used if the API ever sends `splits` before `type` or `group`
+        let mut buffered = None;
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "type" => stats_type = Some(map.next_value::<StatsDisplayName>()?.display_name),
+                "group" => group = Some(map.next_value::<StatsDisplayName>()?.display_name),
+                "splits" => match (&stats_type, &group) {
+                    (Some(stats_type), group) if group.is_some() || !T::GROUPED => {
+                        stats = Some(map.next_value_seed(SplitsSeed {
+                            stats_type,
+                            group: group.as_deref(),
+                            marker: PhantomData,
+                        })?)
+                    }
+                    _ => buffered = Some(map.next_value::<serde_json::Value>()?),
+                },
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+
+        if let Some(stats) = stats {
+            return Ok(stats);
+        }
+
+        let stats_type = stats_type.ok_or_else(|| A::Error::missing_field("type"))?;
+        if T::GROUPED && group.is_none() {
+            return Err(A::Error::missing_field("group"));
+        }
+        let seed = SplitsSeed {
+            stats_type: &stats_type,
+            group: group.as_deref(),
+            marker: PhantomData,
+        };
+
+        match buffered {
+            Some(splits) => seed.deserialize(splits).map_err(A::Error::custom),
+            None => seed.deserialize(UnitDeserializer::new()),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StatsDisplayName {
+    display_name: String,
+}
+
+/// Parses a display name such as "yearByYear" into one of the stat enums
+#[cfg(feature = "mlb")]
+pub(crate) fn display_name<T: serde::de::DeserializeOwned>(name: &str) -> Option<T> {
+    use serde::de::value::{Error, StrDeserializer};
+
+    T::deserialize(StrDeserializer::<Error>::new(name)).ok()
+}
+
+/// Splits are left out or `null` when there are no stats
+pub(crate) fn splits_or_empty<'de, D, T>(de: D) -> Result<Vec<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: serde::Deserialize<'de>,
+{
+    Ok(Option::<Vec<T>>::deserialize(de)?.unwrap_or_default())
+}
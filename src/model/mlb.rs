@@ -1,5 +1,12 @@
+use super::common::{
+    display_name, fail_as_none, from_str_as_option, ordinal_as_option, splits_or_empty, FromSplits,
+    StatsVisitor,
+};
 use chrono::{DateTime, NaiveDate, Utc};
-use serde::{Deserialize, Serialize};
+use serde::{de::Error as _, Deserialize, Serialize};
+use std::{collections::HashMap, marker::PhantomData};
+
+pub use super::common::{AbstractGameState, DetailedState, GameStatus};
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -23,6 +30,307 @@ pub struct Team {
     pub short_name: Option<String>,
     #[serde(default)]
     pub active: bool,
+    pub season: Option<u16>,
+    pub team_code: Option<String>,
+    pub venue: Option<VenueDetail>,
+    pub league: Option<LeagueDetail>,
+    /// Only present for teams that play in a divisional league
+    pub division: Option<DivisionDetail>,
+    pub sport: Option<SportDetail>,
+    /// Cactus or Grapefruit league for major league clubs
+    pub spring_league: Option<LeagueDetail>,
+    pub spring_venue: Option<VenueDetail>,
+    /// Major league club a minor league team is affiliated with
+    pub parent_org_id: Option<u32>,
+    pub parent_org_name: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct VenueDetail {
+    pub id: u32,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub link: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LeagueDetail {
+    /// Missing for independent teams, whose link is `/api/v1/league/null`
+    pub id: Option<u32>,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub link: String,
+    pub abbreviation: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DivisionDetail {
+    pub id: u32,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub link: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SportDetail {
+    pub id: u32,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub link: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaguesResponse {
+    #[serde(default)]
+    pub leagues: Vec<League>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct League {
+    pub id: u32,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub link: String,
+    pub abbreviation: Option<String>,
+    pub name_short: Option<String>,
+    pub season: Option<String>,
+    pub org_code: Option<String>,
+    pub num_teams: Option<u16>,
+    pub num_games: Option<u16>,
+    pub has_wild_card: Option<bool>,
+    pub divisions_in_use: Option<bool>,
+    pub conferences_in_use: Option<bool>,
+    pub sport: Option<SportDetail>,
+    pub sort_order: Option<u16>,
+    #[serde(default)]
+    pub active: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DivisionsResponse {
+    #[serde(default)]
+    pub divisions: Vec<Division>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Division {
+    pub id: u32,
+    #[serde(default)]
+    pub name: String,
+    pub name_short: Option<String>,
+    #[serde(default)]
+    pub link: String,
+    pub abbreviation: Option<String>,
+    pub season: Option<String>,
+    pub league: Option<LeagueDetail>,
+    pub sport: Option<SportDetail>,
+    pub has_wildcard: Option<bool>,
+    pub num_playoff_teams: Option<u8>,
+    pub sort_order: Option<u16>,
+    #[serde(default)]
+    pub active: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct VenuesResponse {
+    #[serde(default)]
+    pub venues: Vec<Venue>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Venue {
+    pub id: u32,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub link: String,
+    pub location: Option<VenueLocation>,
+    pub time_zone: Option<TimeZone>,
+    pub field_info: Option<VenueFieldInfo>,
+    pub active: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct VenueLocation {
+    pub address1: Option<String>,
+    pub city: Option<String>,
+    /// Such as "California"
+    pub state: Option<String>,
+    /// Such as "CA"
+    pub state_abbrev: Option<String>,
+    pub postal_code: Option<String>,
+    pub country: Option<String>,
+    pub phone: Option<String>,
+    pub default_coordinates: Option<Coordinates>,
+    /// Direction from home plate to center field in degrees
+    pub azimuth_angle: Option<f32>,
+    /// Feet above sea level
+    pub elevation: Option<u16>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub struct Coordinates {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeZone {
+    /// Such as "America/Los_Angeles"
+    #[serde(default)]
+    pub id: String,
+    /// Current offset from UTC in hours
+    #[serde(default)]
+    pub offset: i8,
+    /// Such as "PDT"
+    #[serde(default)]
+    pub tz: String,
+}
+
+/// Field dimensions are distances in feet from home plate to the outfield wall
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct VenueFieldInfo {
+    pub capacity: Option<u32>,
+    /// Such as "Grass" or "Artificial Turf"
+    pub turf_type: Option<String>,
+    /// Such as "Open", "Retractable" or "Dome"
+    pub roof_type: Option<String>,
+    pub left_line: Option<u16>,
+    pub left: Option<u16>,
+    pub left_center: Option<u16>,
+    pub center: Option<u16>,
+    pub right_center: Option<u16>,
+    pub right: Option<u16>,
+    pub right_line: Option<u16>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SeasonsResponse {
+    #[serde(default)]
+    pub seasons: Vec<Season>,
+}
+
+/// Key dates of a season, the number of games is found on [`League`]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Season {
+    /// Such as "2019", used for the `season` of queries
+    pub season_id: String,
+    pub has_wildcard: Option<bool>,
+    pub pre_season_start_date: Option<NaiveDate>,
+    pub pre_season_end_date: Option<NaiveDate>,
+    pub season_start_date: Option<NaiveDate>,
+    pub spring_start_date: Option<NaiveDate>,
+    pub spring_end_date: Option<NaiveDate>,
+    pub regular_season_start_date: NaiveDate,
+    pub last_date1st_half: Option<NaiveDate>,
+    pub all_star_date: Option<NaiveDate>,
+    pub first_date2nd_half: Option<NaiveDate>,
+    pub regular_season_end_date: NaiveDate,
+    pub post_season_start_date: Option<NaiveDate>,
+    pub post_season_end_date: Option<NaiveDate>,
+    pub season_end_date: NaiveDate,
+    pub offseason_start_date: Option<NaiveDate>,
+    pub off_season_end_date: Option<NaiveDate>,
+    /// Plate appearances per team game needed to qualify for rate stats
+    pub qualifier_plate_appearances: Option<f32>,
+    /// Outs per team game needed to qualify for rate stats
+    pub qualifier_outs_pitched: Option<f32>,
+}
+
+impl Season {
+    /// Season `date` belongs to, where the preseason counts towards the new
+    /// season and the offseason towards the one that just ended
+    pub fn for_date(seasons: &[Season], date: NaiveDate) -> Option<&Season> {
+        seasons
+            .iter()
+            .filter(|season| season.start_date() <= date)
+            .max_by_key(|season| season.start_date())
+    }
+
+    /// First day of the preseason, or else of the season or regular season
+    pub fn start_date(&self) -> NaiveDate {
+        self.pre_season_start_date
+            .or(self.season_start_date)
+            .unwrap_or(self.regular_season_start_date)
+    }
+
+    /// Whether `date` falls between the start of the preseason and the end of
+    /// the postseason
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.start_date() <= date && date <= self.season_end_date
+    }
+
+    pub fn is_postseason(&self, date: NaiveDate) -> bool {
+        match (self.post_season_start_date, self.post_season_end_date) {
+            (Some(start), Some(end)) => start <= date && date <= end,
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RosterResponse {
+    #[serde(default)]
+    pub roster: Vec<RosterEntry>,
+    #[serde(default)]
+    pub link: String,
+    pub team_id: Option<u32>,
+    pub roster_type: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RosterEntry {
+    pub person: PersonDetail,
+    pub jersey_number: Option<String>,
+    pub position: Position,
+    /// Such as `{"code": "A", "description": "Active"}` or `{"code": "D10", ...}`
+    pub status: Option<CodeDescription>,
+    pub parent_team_id: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RosterType {
+    Active,
+    FortyMan,
+    FullSeason,
+    FullRoster,
+    DepthChart,
+}
+
+impl From<&RosterType> for String {
+    fn from(roster_type: &RosterType) -> String {
+        let s = match roster_type {
+            RosterType::Active => "active",
+            RosterType::FortyMan => "40Man",
+            RosterType::FullSeason => "fullSeason",
+            RosterType::FullRoster => "fullRoster",
+            RosterType::DepthChart => "depthChart",
+        };
+        String::from(s)
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -32,6 +340,90 @@ pub struct ScheduleResponse {
     pub dates: Vec<Schedule>,
 }
 
+/// Parameters for `get_schedule`, every filter is optional
+#[derive(Debug, Clone, Default)]
+pub struct ScheduleQuery {
+    pub start_date: Option<NaiveDate>,
+    pub end_date: Option<NaiveDate>,
+    pub team_id: Option<u32>,
+    pub opponent_id: Option<u32>,
+    /// Such as "R" for regular season, "S" for spring training or "P" for postseason
+    pub game_type: Option<String>,
+    /// Such as "2019"
+    pub season: Option<String>,
+    pub hydrations: Vec<ScheduleHydration>,
+}
+
+impl ScheduleQuery {
+    pub fn new() -> Self {
+        ScheduleQuery::default()
+    }
+
+    /// Games on a single day
+    pub fn date(self, date: NaiveDate) -> Self {
+        self.date_range(date, date)
+    }
+
+    /// Games between `start` and `end`, inclusive
+    pub fn date_range(mut self, start: NaiveDate, end: NaiveDate) -> Self {
+        self.start_date = Some(start);
+        self.end_date = Some(end);
+        self
+    }
+
+    pub fn team(mut self, team_id: u32) -> Self {
+        self.team_id = Some(team_id);
+        self
+    }
+
+    pub fn opponent(mut self, opponent_id: u32) -> Self {
+        self.opponent_id = Some(opponent_id);
+        self
+    }
+
+    pub fn game_type(mut self, game_type: &str) -> Self {
+        self.game_type = Some(game_type.to_owned());
+        self
+    }
+
+    pub fn season(mut self, season: &str) -> Self {
+        self.season = Some(season.to_owned());
+        self
+    }
+
+    /// Populate the optional `ScheduleGame` fields for `hydration`
+    pub fn hydrate(mut self, hydration: ScheduleHydration) -> Self {
+        if !self.hydrations.contains(&hydration) {
+            self.hydrations.push(hydration);
+        }
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScheduleHydration {
+    Linescore,
+    Broadcasts,
+    ProbablePitchers,
+    Venue,
+    Weather,
+    Tickets,
+}
+
+impl From<&ScheduleHydration> for String {
+    fn from(hydration: &ScheduleHydration) -> String {
+        let s = match hydration {
+            ScheduleHydration::Linescore => "linescore",
+            ScheduleHydration::Broadcasts => "broadcasts(all)",
+            ScheduleHydration::ProbablePitchers => "probablePitcher",
+            ScheduleHydration::Venue => "venue(location,timezone,fieldInfo)",
+            ScheduleHydration::Weather => "weather",
+            ScheduleHydration::Tickets => "tickets",
+        };
+        String::from(s)
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Schedule {
@@ -50,9 +442,34 @@ pub struct ScheduleGame {
     pub date: DateTime<Utc>,
     #[serde(default)]
     pub game_type: String,
+    /// Season id such as "2019", schedules don't embed the season itself so use
+    /// `season_of` with the seasons from `get_seasons` to get its dates
     #[serde(default)]
     pub season: String,
+    pub status: GameStatus,
     pub teams: ScheduleGameTeams,
+    /// Location, time zone and field info only present with `ScheduleHydration::Venue`
+    pub venue: Option<Venue>,
+    /// Only present with `ScheduleHydration::Linescore`
+    pub linescore: Option<GameLinescoreResponse>,
+    /// Only present with `ScheduleHydration::Broadcasts`
+    #[serde(default)]
+    pub broadcasts: Vec<ScheduleGameBroadcast>,
+    /// Only present with `ScheduleHydration::Weather`, once announced
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub weather: Option<GameFeedWeather>,
+    /// Only present with `ScheduleHydration::Tickets`
+    #[serde(default)]
+    pub tickets: Vec<ScheduleGameTicket>,
+}
+
+impl ScheduleGame {
+    /// Finds the game's season among `seasons`, such as from `get_seasons`
+    pub fn season_of<'a>(&self, seasons: &'a [Season]) -> Option<&'a Season> {
+        seasons
+            .iter()
+            .find(|season| season.season_id == self.season)
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -68,6 +485,12 @@ pub struct ScheduleGameTeam {
     pub score: Option<u8>,
     #[serde(rename(deserialize = "team"))]
     pub detail: ScheduleGameTeamDetail,
+    /// Record going into the game, or after it once final
+    pub league_record: Option<LeagueRecord>,
+    /// Only present once the game is final
+    pub is_winner: Option<bool>,
+    /// Only present with `ScheduleHydration::ProbablePitchers`, once announced
+    pub probable_pitcher: Option<PersonDetail>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -80,6 +503,33 @@ pub struct ScheduleGameTeamDetail {
     pub link: String,
 }
 
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleGameBroadcast {
+    pub id: u32,
+    #[serde(default)]
+    pub name: String,
+    /// "TV" or "AM"/"FM" for radio
+    #[serde(default)]
+    pub r#type: String,
+    pub call_sign: Option<String>,
+    pub language: Option<String>,
+    /// "home" or "away"
+    pub home_away: Option<String>,
+    #[serde(default)]
+    pub is_national: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleGameTicket {
+    #[serde(default)]
+    pub ticket_type: String,
+    /// Links keyed by platform, such as "desktop" or "mobile"
+    #[serde(default)]
+    pub ticket_links: HashMap<String, String>,
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct GameContentResponse {
@@ -211,8 +661,47 @@ pub struct GameContentArticleMediaImageCutDetail {
 #[derive(Debug, Deserialize, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct GameLinescoreResponse {
-    #[serde(deserialize_with = "fail_as_none")]
-    pub teams: Option<GameLinescoreTeam>,
+    pub current_inning: Option<u8>,
+    pub current_inning_ordinal: Option<String>,
+    pub inning_state: Option<String>,
+    pub inning_half: Option<String>,
+    #[serde(default)]
+    pub is_top_inning: bool,
+    pub scheduled_innings: Option<u8>,
+    #[serde(default)]
+    pub innings: Vec<GameLinescoreInning>,
+    pub teams: GameLinescoreTeams,
+    #[serde(default)]
+    pub defense: GameLinescoreDefense,
+    #[serde(default)]
+    pub offense: GameLinescoreOffense,
+    pub balls: Option<u8>,
+    pub strikes: Option<u8>,
+    pub outs: Option<u8>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameLinescoreInning {
+    pub num: u8,
+    #[serde(default)]
+    pub ordinal_num: String,
+    pub home: GameLinescoreInningTeam,
+    pub away: GameLinescoreInningTeam,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameLinescoreInningTeam {
+    /// `None` when the half inning wasn't played, such as the bottom of
+    /// the 9th when the home team is ahead
+    pub runs: Option<u32>,
+    #[serde(default)]
+    pub hits: u32,
+    #[serde(default)]
+    pub errors: u32,
+    #[serde(default)]
+    pub left_on_base: u32,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -225,6 +714,8 @@ pub struct GameLinescoreTeams {
 #[derive(Debug, Deserialize, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct GameLinescoreTeam {
+    #[serde(rename(deserialize = "team"))]
+    pub detail: Option<GameLinescoreTeamDetail>,
     #[serde(default)]
     pub runs: u32,
     #[serde(default)]
@@ -235,14 +726,1481 @@ pub struct GameLinescoreTeam {
     pub left_on_base: u32,
 }
 
-fn fail_as_none<'de, D, T>(de: D) -> Result<Option<T>, D::Error>
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameLinescoreTeamDetail {
+    pub id: u32,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub link: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GameLinescoreDefense {
+    pub pitcher: Option<PersonDetail>,
+    pub catcher: Option<PersonDetail>,
+    pub first: Option<PersonDetail>,
+    pub second: Option<PersonDetail>,
+    pub third: Option<PersonDetail>,
+    pub shortstop: Option<PersonDetail>,
+    pub left: Option<PersonDetail>,
+    pub center: Option<PersonDetail>,
+    pub right: Option<PersonDetail>,
+    pub team: Option<GameLinescoreTeamDetail>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GameLinescoreOffense {
+    pub batter: Option<PersonDetail>,
+    pub on_deck: Option<PersonDetail>,
+    pub in_hole: Option<PersonDetail>,
+    pub pitcher: Option<PersonDetail>,
+    /// Runner on first base
+    pub first: Option<PersonDetail>,
+    /// Runner on second base
+    pub second: Option<PersonDetail>,
+    /// Runner on third base
+    pub third: Option<PersonDetail>,
+    pub team: Option<GameLinescoreTeamDetail>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedResponse {
+    pub game_pk: u64,
+    #[serde(default)]
+    pub link: String,
+    pub meta_data: Option<GameFeedMetaData>,
+    pub game_data: GameFeedGameData,
+    pub live_data: GameFeedLiveData,
+}
+
+/// Change between two versions of a live game feed, see `MlbClient::watch_game`
+#[derive(Debug, Clone)]
+pub enum GameEvent {
+    /// Abstract or detailed state changed, such as a delay or the game going final
+    Status(GameStatus),
+    Score {
+        away: u32,
+        home: u32,
+    },
+    Inning {
+        inning: u8,
+        is_top_inning: bool,
+    },
+    /// At bat that was completed
+    Play(Box<GameFeedPlay>),
+}
+
+impl GameFeedResponse {
+    /// Events describing what changed from `previous` to this version of the feed
+    pub fn events_since(&self, previous: &GameFeedResponse) -> Vec<GameEvent> {
+        let mut events = vec![];
+
+        let plays = &self.live_data.plays.all_plays;
+        let previous_plays = &previous.live_data.plays.all_plays;
+        for (idx, play) in plays.iter().enumerate() {
+            let was_complete = previous_plays.get(idx).is_some_and(|p| p.about.is_complete);
+            if play.about.is_complete && !was_complete {
+                events.push(GameEvent::Play(Box::new(play.clone())));
+            }
+        }
+
+        let teams = &self.live_data.linescore.teams;
+        let previous_teams = &previous.live_data.linescore.teams;
+        if teams.away.runs != previous_teams.away.runs
+            || teams.home.runs != previous_teams.home.runs
+        {
+            events.push(GameEvent::Score {
+                away: teams.away.runs,
+                home: teams.home.runs,
+            });
+        }
+
+        let linescore = &self.live_data.linescore;
+        let previous_linescore = &previous.live_data.linescore;
+        if let Some(inning) = linescore.current_inning {
+            if linescore.current_inning != previous_linescore.current_inning
+                || linescore.is_top_inning != previous_linescore.is_top_inning
+            {
+                events.push(GameEvent::Inning {
+                    inning,
+                    is_top_inning: linescore.is_top_inning,
+                });
+            }
+        }
+
+        let status = &self.game_data.status;
+        let previous_status = &previous.game_data.status;
+        if status.abstract_game_state != previous_status.abstract_game_state
+            || status.detailed_state != previous_status.detailed_state
+        {
+            events.push(GameEvent::Status(status.clone()));
+        }
+
+        events
+    }
+}
+
+/// Live feed kept alongside its raw JSON, so it can be refreshed from the
+/// `diffPatch` operations of `MlbClient::update_game_feed`
+#[derive(Debug, Clone)]
+pub struct LiveGameFeed {
+    pub(crate) feed: GameFeedResponse,
+    pub(crate) raw: serde_json::Value,
+}
+
+impl LiveGameFeed {
+    pub fn feed(&self) -> &GameFeedResponse {
+        &self.feed
+    }
+
+    pub fn into_feed(self) -> GameFeedResponse {
+        self.feed
+    }
+
+    /// Timecode of this version of the feed, diffs are requested from it
+    pub fn time_stamp(&self) -> Option<&str> {
+        self.feed
+            .meta_data
+            .as_ref()
+            .map(|meta_data| meta_data.time_stamp.as_str())
+            .filter(|time_stamp| !time_stamp.is_empty())
+    }
+}
+
+/// One batch of JSON patch operations from `feed/live/diffPatch`
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedDiff {
+    pub diff: json_patch::Patch,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedMetaData {
+    /// Suggested number of seconds to wait before polling again
+    #[serde(default)]
+    pub wait: u32,
+    /// Timecode of this version of the feed, formatted as `yyyymmdd_hhmmss`
+    #[serde(default)]
+    pub time_stamp: String,
+    #[serde(default)]
+    pub game_events: Vec<String>,
+    #[serde(default)]
+    pub logical_events: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedGameData {
+    pub game: GameFeedGame,
+    pub datetime: GameFeedDateTime,
+    pub status: GameStatus,
+    pub teams: GameFeedTeams,
+    /// Players on either roster, keyed by `ID{player_id}`
+    #[serde(default)]
+    pub players: HashMap<String, Person>,
+    pub venue: Option<GameFeedVenue>,
+    pub weather: Option<GameFeedWeather>,
+    #[serde(default)]
+    pub probable_pitchers: GameFeedProbablePitchers,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedGame {
+    pub pk: u64,
+    #[serde(default)]
+    pub r#type: String,
+    pub double_header: Option<String>,
+    pub id: Option<String>,
+    #[serde(default)]
+    pub season: String,
+    pub game_number: Option<u8>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedDateTime {
+    pub date_time: DateTime<Utc>,
+    pub original_date: Option<NaiveDate>,
+    pub day_night: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedTeams {
+    pub away: Team,
+    pub home: Team,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedVenue {
+    pub id: u32,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub link: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedWeather {
+    #[serde(default)]
+    pub condition: String,
+    /// Temperature in Fahrenheit
+    #[serde(default)]
+    pub temp: String,
+    #[serde(default)]
+    pub wind: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedProbablePitchers {
+    pub away: Option<PersonDetail>,
+    pub home: Option<PersonDetail>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PeopleResponse {
+    #[serde(default)]
+    pub people: Vec<Person>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Person {
+    pub id: u32,
+    #[serde(default)]
+    pub full_name: String,
+    #[serde(default)]
+    pub link: String,
+    pub first_name: Option<String>,
+    pub middle_name: Option<String>,
+    pub last_name: Option<String>,
+    pub nick_name: Option<String>,
+    pub primary_number: Option<String>,
+    pub birth_date: Option<NaiveDate>,
+    pub current_age: Option<u8>,
+    pub birth_city: Option<String>,
+    pub birth_state_province: Option<String>,
+    pub birth_country: Option<String>,
+    /// Height such as `6' 2"`
+    pub height: Option<String>,
+    /// Weight in pounds
+    pub weight: Option<u16>,
+    #[serde(default)]
+    pub active: bool,
+    /// Only present when requested through the people endpoints
+    pub current_team: Option<TeamDetail>,
+    pub primary_position: Option<Position>,
+    pub use_name: Option<String>,
+    pub boxscore_name: Option<String>,
+    pub gender: Option<String>,
+    #[serde(default)]
+    pub is_player: bool,
+    pub draft_year: Option<u16>,
+    pub mlb_debut_date: Option<NaiveDate>,
+    pub last_played_date: Option<NaiveDate>,
+    pub bat_side: Option<CodeDescription>,
+    pub pitch_hand: Option<CodeDescription>,
+    pub name_slug: Option<String>,
+    /// Top of the strike zone in feet
+    pub strike_zone_top: Option<f32>,
+    /// Bottom of the strike zone in feet
+    pub strike_zone_bottom: Option<f32>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonDetail {
+    pub id: u32,
+    #[serde(default)]
+    pub full_name: String,
+    #[serde(default)]
+    pub link: String,
+}
+
+impl Person {
+    /// Headshot image `width` pixels wide, falls back to a generic silhouette
+    pub fn headshot_url(&self, width: u32) -> String {
+        headshot_url(self.id, width)
+    }
+}
+
+impl PersonDetail {
+    /// Headshot image `width` pixels wide, falls back to a generic silhouette
+    pub fn headshot_url(&self, width: u32) -> String {
+        headshot_url(self.id, width)
+    }
+}
+
+fn headshot_url(person_id: u32, width: u32) -> String {
+    format!(
+        "https://img.mlbstatic.com/mlb-photos/image/upload/d_people:generic:headshot:67:current.png/w_{},q_auto:best/v1/people/{}/headshot/67/current",
+        width, person_id
+    )
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Position {
+    #[serde(default)]
+    pub code: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub r#type: String,
+    #[serde(default)]
+    pub abbreviation: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CodeDescription {
+    #[serde(default)]
+    pub code: String,
+    #[serde(default)]
+    pub description: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedLiveData {
+    pub plays: GameFeedPlays,
+    pub linescore: GameLinescoreResponse,
+    pub boxscore: GameBoxscoreResponse,
+    #[serde(default)]
+    pub decisions: GameFeedDecisions,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedPlays {
+    #[serde(default)]
+    pub all_plays: Vec<GameFeedPlay>,
+    pub current_play: Option<GameFeedPlay>,
+    /// Indices into `all_plays`
+    #[serde(default)]
+    pub scoring_plays: Vec<usize>,
+    #[serde(default)]
+    pub plays_by_inning: Vec<GameFeedPlaysByInning>,
+}
+
+impl GameFeedPlays {
+    pub fn scoring(&self) -> impl Iterator<Item = &GameFeedPlay> {
+        self.scoring_plays
+            .iter()
+            .filter_map(move |idx| self.all_plays.get(*idx))
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedPlaysByInning {
+    #[serde(default)]
+    pub start_index: usize,
+    #[serde(default)]
+    pub end_index: usize,
+    #[serde(default)]
+    pub top: Vec<usize>,
+    #[serde(default)]
+    pub bottom: Vec<usize>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedPlay {
+    pub result: GameFeedPlayResult,
+    pub about: GameFeedPlayAbout,
+    #[serde(default)]
+    pub count: GameFeedCount,
+    pub matchup: GameFeedMatchup,
+    #[serde(default)]
+    pub runners: Vec<GameFeedRunner>,
+    #[serde(default)]
+    pub play_events: Vec<GameFeedPlayEvent>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedPlayResult {
+    #[serde(default)]
+    pub r#type: String,
+    /// Missing until the at bat is complete
+    pub event: Option<String>,
+    pub event_type: Option<String>,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub rbi: u8,
+    #[serde(default)]
+    pub away_score: u8,
+    #[serde(default)]
+    pub home_score: u8,
+    #[serde(default)]
+    pub is_out: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedPlayAbout {
+    pub at_bat_index: usize,
+    #[serde(default)]
+    pub half_inning: String,
+    #[serde(default)]
+    pub is_top_inning: bool,
+    #[serde(default)]
+    pub inning: u8,
+    pub start_time: Option<DateTime<Utc>>,
+    pub end_time: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub is_complete: bool,
+    #[serde(default)]
+    pub is_scoring_play: bool,
+    #[serde(default)]
+    pub has_review: bool,
+    #[serde(default)]
+    pub has_out: bool,
+    #[serde(default)]
+    pub captivating_index: u8,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedCount {
+    #[serde(default)]
+    pub balls: u8,
+    #[serde(default)]
+    pub strikes: u8,
+    #[serde(default)]
+    pub outs: u8,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedMatchup {
+    pub batter: PersonDetail,
+    pub bat_side: Option<CodeDescription>,
+    pub pitcher: PersonDetail,
+    pub pitch_hand: Option<CodeDescription>,
+    pub splits: Option<GameFeedMatchupSplits>,
+    pub post_on_first: Option<PersonDetail>,
+    pub post_on_second: Option<PersonDetail>,
+    pub post_on_third: Option<PersonDetail>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedMatchupSplits {
+    /// Such as "vs_RHP"
+    #[serde(default)]
+    pub batter: String,
+    /// Such as "vs_LHB"
+    #[serde(default)]
+    pub pitcher: String,
+    /// Such as "Empty", "RISP" or "Loaded"
+    #[serde(default)]
+    pub men_on_base: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedRunner {
+    pub movement: GameFeedRunnerMovement,
+    pub details: GameFeedRunnerDetails,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedRunnerMovement {
+    /// Base such as "1B", `None` for the batter
+    pub start: Option<String>,
+    /// Base such as "2B" or "score", `None` when the runner is out
+    pub end: Option<String>,
+    pub out_base: Option<String>,
+    #[serde(default)]
+    pub is_out: bool,
+    pub out_number: Option<u8>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedRunnerDetails {
+    #[serde(default)]
+    pub event: String,
+    #[serde(default)]
+    pub event_type: String,
+    pub movement_reason: Option<String>,
+    pub runner: PersonDetail,
+    pub responsible_pitcher: Option<PersonDetail>,
+    #[serde(default)]
+    pub is_scoring_event: bool,
+    #[serde(default)]
+    pub rbi: bool,
+    #[serde(default)]
+    pub earned: bool,
+    /// Index into the play's `play_events`
+    pub play_index: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedPlayEvent {
+    pub details: GameFeedPlayEventDetails,
+    #[serde(default)]
+    pub count: GameFeedCount,
+    pub pitch_data: Option<GameFeedPitchData>,
+    pub hit_data: Option<GameFeedHitData>,
+    #[serde(default)]
+    pub index: usize,
+    pub play_id: Option<String>,
+    pub pitch_number: Option<u8>,
+    pub start_time: Option<DateTime<Utc>>,
+    pub end_time: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub is_pitch: bool,
+    /// "pitch", "action" or "pickoff"
+    #[serde(default)]
+    pub r#type: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedPlayEventDetails {
+    pub call: Option<CodeDescription>,
+    #[serde(default)]
+    pub description: String,
+    pub code: Option<String>,
+    /// Only present for non-pitch events such as substitutions or stolen bases
+    pub event: Option<String>,
+    #[serde(default)]
+    pub is_in_play: bool,
+    #[serde(default)]
+    pub is_strike: bool,
+    #[serde(default)]
+    pub is_ball: bool,
+    /// Pitch type, such as `{"code": "FF", "description": "Four-Seam Fastball"}`
+    pub r#type: Option<CodeDescription>,
+    #[serde(default)]
+    pub has_review: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedPitchData {
+    /// Release speed in mph
+    pub start_speed: Option<f32>,
+    /// Speed crossing the plate in mph
+    pub end_speed: Option<f32>,
+    pub strike_zone_top: Option<f32>,
+    pub strike_zone_bottom: Option<f32>,
+    #[serde(default)]
+    pub coordinates: GameFeedPitchCoordinates,
+    pub breaks: Option<GameFeedPitchBreaks>,
+    /// Gameday zone, 1 through 9 inside the strike zone and 11 through 14 outside
+    pub zone: Option<u8>,
+    pub type_confidence: Option<f32>,
+    pub plate_time: Option<f32>,
+    pub extension: Option<f32>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedPitchCoordinates {
+    /// Horizontal position crossing the plate in feet, from the catcher's view
+    pub p_x: Option<f32>,
+    /// Height crossing the plate in feet
+    pub p_z: Option<f32>,
+    pub pfx_x: Option<f32>,
+    pub pfx_z: Option<f32>,
+    pub a_x: Option<f32>,
+    pub a_y: Option<f32>,
+    pub a_z: Option<f32>,
+    pub v_x0: Option<f32>,
+    pub v_y0: Option<f32>,
+    pub v_z0: Option<f32>,
+    pub x0: Option<f32>,
+    pub y0: Option<f32>,
+    pub z0: Option<f32>,
+    /// Gameday pixel coordinates
+    pub x: Option<f32>,
+    pub y: Option<f32>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedPitchBreaks {
+    pub break_angle: Option<f32>,
+    pub break_length: Option<f32>,
+    pub break_y: Option<f32>,
+    /// Revolutions per minute
+    pub spin_rate: Option<u32>,
+    pub spin_direction: Option<u32>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedHitData {
+    /// Exit velocity in mph
+    pub launch_speed: Option<f32>,
+    /// Vertical angle in degrees
+    pub launch_angle: Option<f32>,
+    /// Distance in feet
+    pub total_distance: Option<f32>,
+    /// Such as "ground_ball", "line_drive" or "fly_ball"
+    pub trajectory: Option<String>,
+    pub hardness: Option<String>,
+    /// Fielder position number the ball was hit to
+    pub location: Option<String>,
+    #[serde(default)]
+    pub coordinates: GameFeedHitCoordinates,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedHitCoordinates {
+    pub coord_x: Option<f32>,
+    pub coord_y: Option<f32>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedDecisions {
+    pub winner: Option<PersonDetail>,
+    pub loser: Option<PersonDetail>,
+    pub save: Option<PersonDetail>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameBoxscoreResponse {
+    pub teams: GameBoxscoreTeams,
+    #[serde(default)]
+    pub officials: Vec<GameBoxscoreOfficial>,
+    /// Game notes such as winning pitcher, time and attendance
+    #[serde(default)]
+    pub info: Vec<GameBoxscoreInfoField>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameBoxscoreTeams {
+    pub away: GameBoxscoreTeam,
+    pub home: GameBoxscoreTeam,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameBoxscoreTeam {
+    pub team: Team,
+    #[serde(default)]
+    pub team_stats: GameBoxscoreStats,
+    /// Players keyed by `ID{player_id}`
+    #[serde(default)]
+    pub players: HashMap<String, GameBoxscorePlayer>,
+    #[serde(default)]
+    pub batters: Vec<u32>,
+    #[serde(default)]
+    pub pitchers: Vec<u32>,
+    #[serde(default)]
+    pub bench: Vec<u32>,
+    #[serde(default)]
+    pub bullpen: Vec<u32>,
+    #[serde(default)]
+    pub batting_order: Vec<u32>,
+    #[serde(default)]
+    pub info: Vec<GameBoxscoreInfo>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameBoxscorePlayer {
+    pub person: PersonDetail,
+    pub jersey_number: Option<String>,
+    pub position: Option<Position>,
+    pub status: Option<CodeDescription>,
+    pub parent_team_id: Option<u32>,
+    /// Spot in the order such as "300", substitutes get "301", "302", ...
+    pub batting_order: Option<String>,
+    #[serde(default)]
+    pub stats: GameBoxscoreStats,
+    #[serde(default)]
+    pub season_stats: GameBoxscoreStats,
+    pub game_status: Option<GameBoxscorePlayerGameStatus>,
+}
+
+/// Stat groups that don't apply, such as pitching for a position player, are `None`
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GameBoxscoreStats {
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub batting: Option<BattingStats>,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub pitching: Option<PitchingStats>,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub fielding: Option<FieldingStats>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameBoxscorePlayerGameStatus {
+    #[serde(default)]
+    pub is_current_batter: bool,
+    #[serde(default)]
+    pub is_current_pitcher: bool,
+    #[serde(default)]
+    pub is_on_bench: bool,
+    #[serde(default)]
+    pub is_substitute: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameBoxscoreInfo {
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub field_list: Vec<GameBoxscoreInfoField>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameBoxscoreInfoField {
+    #[serde(default)]
+    pub label: String,
+    pub value: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameBoxscoreOfficial {
+    pub official: PersonDetail,
+    /// Such as "Home Plate" or "First Base"
+    #[serde(default)]
+    pub official_type: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct BattingStats {
+    pub games_played: u32,
+    pub fly_outs: u32,
+    pub ground_outs: u32,
+    pub runs: u32,
+    pub doubles: u32,
+    pub triples: u32,
+    pub home_runs: u32,
+    pub strike_outs: u32,
+    pub base_on_balls: u32,
+    pub intentional_walks: u32,
+    pub hits: u32,
+    pub hit_by_pitch: u32,
+    pub at_bats: u32,
+    pub caught_stealing: u32,
+    pub stolen_bases: u32,
+    pub ground_into_double_play: u32,
+    pub plate_appearances: u32,
+    pub total_bases: u32,
+    pub rbi: u32,
+    pub left_on_base: u32,
+    pub sac_bunts: u32,
+    pub sac_flies: u32,
+    pub air_outs: u32,
+    pub number_of_pitches: u32,
+    pub catchers_interference: u32,
+    /// Rate stats are formatted strings such as ".273", or ".---" when undefined
+    pub avg: Option<String>,
+    pub obp: Option<String>,
+    pub slg: Option<String>,
+    pub ops: Option<String>,
+    pub stolen_base_percentage: Option<String>,
+    pub babip: Option<String>,
+    pub ground_outs_to_airouts: Option<String>,
+    pub at_bats_per_home_run: Option<String>,
+    /// Game line such as "1-4 | HR, 2 RBI"
+    pub summary: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PitchingStats {
+    pub games_played: u32,
+    pub games_started: u32,
+    pub fly_outs: u32,
+    pub ground_outs: u32,
+    pub air_outs: u32,
+    pub runs: u32,
+    pub doubles: u32,
+    pub triples: u32,
+    pub home_runs: u32,
+    pub strike_outs: u32,
+    pub base_on_balls: u32,
+    pub intentional_walks: u32,
+    pub hits: u32,
+    pub hit_by_pitch: u32,
+    pub at_bats: u32,
+    pub number_of_pitches: u32,
+    /// Innings such as "6.2", where the decimal is outs rather than tenths
+    pub innings_pitched: Option<String>,
+    pub wins: u32,
+    pub losses: u32,
+    pub saves: u32,
+    pub save_opportunities: u32,
+    pub holds: u32,
+    pub blown_saves: u32,
+    pub earned_runs: u32,
+    pub batters_faced: u32,
+    pub outs: u32,
+    pub pitches_thrown: u32,
+    pub balls: u32,
+    pub strikes: u32,
+    pub hit_batsmen: u32,
+    pub balks: u32,
+    pub wild_pitches: u32,
+    pub inherited_runners: u32,
+    pub inherited_runners_scored: u32,
+    pub games_pitched: u32,
+    pub games_finished: u32,
+    pub complete_games: u32,
+    pub shutouts: u32,
+    pub pickoffs: u32,
+    pub era: Option<String>,
+    pub whip: Option<String>,
+    pub strike_percentage: Option<String>,
+    /// Opponent rate stats
+    pub avg: Option<String>,
+    pub obp: Option<String>,
+    pub slg: Option<String>,
+    pub ops: Option<String>,
+    pub win_percentage: Option<String>,
+    pub strikeout_walk_ratio: Option<String>,
+    pub strikeouts_per9_inn: Option<String>,
+    pub walks_per9_inn: Option<String>,
+    pub hits_per9_inn: Option<String>,
+    pub runs_scored_per9: Option<String>,
+    pub home_runs_per9: Option<String>,
+    pub pitches_per_inning: Option<String>,
+    /// Decision such as "(W, 3-1)"
+    pub note: Option<String>,
+    pub summary: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct FieldingStats {
+    pub assists: u32,
+    pub put_outs: u32,
+    pub errors: u32,
+    pub chances: u32,
+    /// Fielding percentage such as ".985"
+    pub fielding: Option<String>,
+    pub caught_stealing: u32,
+    pub passed_ball: u32,
+    pub stolen_bases: u32,
+    pub pickoffs: u32,
+    pub games: u32,
+    pub games_started: u32,
+    pub double_plays: u32,
+    pub triple_plays: u32,
+    pub throwing_errors: u32,
+    /// Innings such as "1204.1", where the decimal is outs rather than tenths
+    pub innings: Option<String>,
+    pub range_factor_per_game: Option<String>,
+    /// Only present on season and career splits, one split per position
+    pub position: Option<Position>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonStatsResponse {
+    #[serde(default)]
+    pub stats: Vec<PersonStats>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum StatsType {
+    Season,
+    Career,
+    YearByYear,
+    GameLog,
+    VsTeam,
+    HomeAndAway,
+    ByMonth,
+    ByDayOfWeek,
+}
+
+impl From<&StatsType> for String {
+    fn from(stats_type: &StatsType) -> String {
+        let s = match stats_type {
+            StatsType::Season => "season",
+            StatsType::Career => "career",
+            StatsType::YearByYear => "yearByYear",
+            StatsType::GameLog => "gameLog",
+            StatsType::VsTeam => "vsTeam",
+            StatsType::HomeAndAway => "homeAndAway",
+            StatsType::ByMonth => "byMonth",
+            StatsType::ByDayOfWeek => "byDayOfWeek",
+        };
+        String::from(s)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum StatsGroup {
+    Hitting,
+    Pitching,
+    Fielding,
+}
+
+impl From<&StatsGroup> for String {
+    fn from(group: &StatsGroup) -> String {
+        let s = match group {
+            StatsGroup::Hitting => "hitting",
+            StatsGroup::Pitching => "pitching",
+            StatsGroup::Fielding => "fielding",
+        };
+        String::from(s)
+    }
+}
+
+/// Parameters for `get_person_stats`
+///
+/// Defaults to the current season when `season`, such as "2019", isn't set
+#[derive(Debug, Clone)]
+pub struct StatsQuery {
+    pub stats: StatsType,
+    pub group: StatsGroup,
+    pub season: Option<String>,
+}
+
+impl StatsQuery {
+    pub fn new(stats: StatsType, group: StatsGroup) -> Self {
+        StatsQuery {
+            stats,
+            group,
+            season: None,
+        }
+    }
+
+    pub fn season(mut self, season: &str) -> Self {
+        self.season = Some(season.to_owned());
+        self
+    }
+}
+
+/// Stat splits for one stat type, typed by the group they were requested for
+#[derive(Debug, Serialize, Clone)]
+pub enum PersonStats {
+    Hitting(PersonStatsGroup<BattingStats>),
+    Pitching(PersonStatsGroup<PitchingStats>),
+    Fielding(PersonStatsGroup<FieldingStats>),
+}
+
+impl PersonStats {
+    pub fn group(&self) -> StatsGroup {
+        match self {
+            PersonStats::Hitting(_) => StatsGroup::Hitting,
+            PersonStats::Pitching(_) => StatsGroup::Pitching,
+            PersonStats::Fielding(_) => StatsGroup::Fielding,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonStatsGroup<T> {
+    #[serde(default, deserialize_with = "fail_as_none")]
+    pub stats_type: Option<StatsType>,
+    pub splits: Vec<StatsSplit<T>>,
+}
+
+/// One stat line. Which of the optional fields are present depends on the
+/// stat type, e.g. `date` and `game` for game logs, `month` for by month.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsSplit<T> {
+    pub season: Option<String>,
+    pub stat: T,
+    pub team: Option<TeamDetail>,
+    pub opponent: Option<TeamDetail>,
+    pub game_type: Option<String>,
+    /// Number of teams played for, present on seasons with a trade
+    pub num_teams: Option<u8>,
+    pub date: Option<NaiveDate>,
+    pub game: Option<StatsSplitGame>,
+    pub is_home: Option<bool>,
+    pub is_win: Option<bool>,
+    pub month: Option<u8>,
+    /// 1 for Sunday through 7 for Saturday
+    pub day_of_week: Option<u8>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsSplitGame {
+    pub game_pk: u64,
+}
+
+impl<'de> Deserialize<'de> for PersonStats {
+    fn deserialize<D>(de: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        de.deserialize_map(StatsVisitor(PhantomData))
+    }
+}
+
+impl FromSplits for PersonStats {
+    const GROUPED: bool = true;
+
+    fn from_splits<'de, D>(
+        stats_type: &str,
+        group: Option<&str>,
+        splits: D,
+    ) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let group = group.unwrap_or_default();
+        let stats_type = display_name(stats_type);
+        let group = display_name(group).ok_or_else(|| {
+            D::Error::unknown_variant(group, &["hitting", "pitching", "fielding"])
+        })?;
+
+        let stats = match group {
+            StatsGroup::Hitting => PersonStats::Hitting(PersonStatsGroup {
+                stats_type,
+                splits: splits_or_empty(splits)?,
+            }),
+            StatsGroup::Pitching => PersonStats::Pitching(PersonStatsGroup {
+                stats_type,
+                splits: splits_or_empty(splits)?,
+            }),
+            StatsGroup::Fielding => PersonStats::Fielding(PersonStatsGroup {
+                stats_type,
+                splits: splits_or_empty(splits)?,
+            }),
+        };
+
+        Ok(stats)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamStatsResponse {
+    #[serde(default)]
+    pub stats: Vec<TeamStats>,
+}
+
+/// Team season totals or their ranks among all teams, by group
+#[derive(Debug, Serialize, Clone)]
+pub enum TeamStats {
+    Hitting(Vec<StatsSplit<BattingStats>>),
+    Pitching(Vec<StatsSplit<PitchingStats>>),
+    HittingRankings(Vec<StatsSplit<BattingRankings>>),
+    PitchingRankings(Vec<StatsSplit<PitchingRankings>>),
+    /// Groups without a typed stat line, such as fielding, with their raw splits
+    Other {
+        stats_type: String,
+        group: String,
+        splits: Vec<serde_json::Value>,
+    },
+}
+
+/// League ranks, where 1 is the best value
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BattingRankings {
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub runs: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub hits: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub doubles: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub triples: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub home_runs: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub rbi: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub base_on_balls: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub strike_outs: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub stolen_bases: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub avg: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub obp: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub slg: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub ops: Option<u16>,
+}
+
+/// League ranks, where 1 is the best value
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PitchingRankings {
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub wins: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub saves: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub hits: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub runs: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub earned_runs: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub home_runs: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub base_on_balls: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub strike_outs: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub era: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub whip: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub avg: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub strikeouts_per9_inn: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub walks_per9_inn: Option<u16>,
+}
+
+impl<'de> Deserialize<'de> for TeamStats {
+    fn deserialize<D>(de: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        de.deserialize_map(StatsVisitor(PhantomData))
+    }
+}
+
+impl FromSplits for TeamStats {
+    const GROUPED: bool = true;
+
+    fn from_splits<'de, D>(
+        stats_type: &str,
+        group: Option<&str>,
+        splits: D,
+    ) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let group = group.unwrap_or_default();
+        let rankings = stats_type == "regularSeasonStatRankings";
+
+        let stats = match (display_name(group), rankings) {
+            (Some(StatsGroup::Hitting), false) => TeamStats::Hitting(splits_or_empty(splits)?),
+            (Some(StatsGroup::Pitching), false) => TeamStats::Pitching(splits_or_empty(splits)?),
+            (Some(StatsGroup::Hitting), true) => {
+                TeamStats::HittingRankings(splits_or_empty(splits)?)
+            }
+            (Some(StatsGroup::Pitching), true) => {
+                TeamStats::PitchingRankings(splits_or_empty(splits)?)
+            }
+            _ => TeamStats::Other {
+                stats_type: stats_type.to_owned(),
+                group: group.to_owned(),
+                splits: splits_or_empty(splits)?,
+            },
+        };
+
+        Ok(stats)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LeagueLeadersResponse {
+    #[serde(default)]
+    pub league_leaders: Vec<LeagueLeaders>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum LeaderCategory {
+    HomeRuns,
+    BattingAverage,
+    RunsBattedIn,
+    Hits,
+    Runs,
+    Doubles,
+    Triples,
+    StolenBases,
+    Walks,
+    OnBasePercentage,
+    SluggingPercentage,
+    OnBasePlusSlugging,
+    Wins,
+    EarnedRunAverage,
+    Strikeouts,
+    Saves,
+    Holds,
+    InningsPitched,
+    #[serde(rename = "walksAndHitsPerInningPitched")]
+    Whip,
+}
+
+impl From<&LeaderCategory> for String {
+    fn from(category: &LeaderCategory) -> String {
+        let s = match category {
+            LeaderCategory::HomeRuns => "homeRuns",
+            LeaderCategory::BattingAverage => "battingAverage",
+            LeaderCategory::RunsBattedIn => "runsBattedIn",
+            LeaderCategory::Hits => "hits",
+            LeaderCategory::Runs => "runs",
+            LeaderCategory::Doubles => "doubles",
+            LeaderCategory::Triples => "triples",
+            LeaderCategory::StolenBases => "stolenBases",
+            LeaderCategory::Walks => "walks",
+            LeaderCategory::OnBasePercentage => "onBasePercentage",
+            LeaderCategory::SluggingPercentage => "sluggingPercentage",
+            LeaderCategory::OnBasePlusSlugging => "onBasePlusSlugging",
+            LeaderCategory::Wins => "wins",
+            LeaderCategory::EarnedRunAverage => "earnedRunAverage",
+            LeaderCategory::Strikeouts => "strikeouts",
+            LeaderCategory::Saves => "saves",
+            LeaderCategory::Holds => "holds",
+            LeaderCategory::InningsPitched => "inningsPitched",
+            LeaderCategory::Whip => "walksAndHitsPerInningPitched",
+        };
+        String::from(s)
+    }
+}
+
+impl LeaderCategory {
+    /// Categories such as strikeouts exist for both hitting and pitching, so
+    /// the group is sent along with the category
+    pub(crate) fn stat_group(&self) -> &'static str {
+        match self {
+            LeaderCategory::HomeRuns => "hitting",
+            LeaderCategory::BattingAverage => "hitting",
+            LeaderCategory::RunsBattedIn => "hitting",
+            LeaderCategory::Hits => "hitting",
+            LeaderCategory::Runs => "hitting",
+            LeaderCategory::Doubles => "hitting",
+            LeaderCategory::Triples => "hitting",
+            LeaderCategory::StolenBases => "hitting",
+            LeaderCategory::Walks => "hitting",
+            LeaderCategory::OnBasePercentage => "hitting",
+            LeaderCategory::SluggingPercentage => "hitting",
+            LeaderCategory::OnBasePlusSlugging => "hitting",
+            LeaderCategory::Wins => "pitching",
+            LeaderCategory::EarnedRunAverage => "pitching",
+            LeaderCategory::Strikeouts => "pitching",
+            LeaderCategory::Saves => "pitching",
+            LeaderCategory::Holds => "pitching",
+            LeaderCategory::InningsPitched => "pitching",
+            LeaderCategory::Whip => "pitching",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LeagueLeaders {
+    #[serde(default, deserialize_with = "fail_as_none")]
+    pub leader_category: Option<LeaderCategory>,
+    pub season: Option<String>,
+    #[serde(default)]
+    pub leaders: Vec<Leader>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Leader {
+    pub rank: u16,
+    /// Formatted value such as ".335"
+    #[serde(default)]
+    pub value: String,
+    pub person: PersonDetail,
+    pub team: Option<TeamDetail>,
+    pub season: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct StandingsResponse {
+    #[serde(default)]
+    pub records: Vec<StandingsRecord>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum StandingsType {
+    RegularSeason,
+    WildCard,
+    DivisionLeaders,
+    WildCardWithLeaders,
+    FirstHalf,
+    SecondHalf,
+    SpringTraining,
+    Postseason,
+    ByDivision,
+    ByConference,
+    ByLeague,
+    ByOrganization,
+}
+
+impl From<&StandingsType> for String {
+    fn from(standings_type: &StandingsType) -> String {
+        let s = match standings_type {
+            StandingsType::RegularSeason => "regularSeason",
+            StandingsType::WildCard => "wildCard",
+            StandingsType::DivisionLeaders => "divisionLeaders",
+            StandingsType::WildCardWithLeaders => "wildCardWithLeaders",
+            StandingsType::FirstHalf => "firstHalf",
+            StandingsType::SecondHalf => "secondHalf",
+            StandingsType::SpringTraining => "springTraining",
+            StandingsType::Postseason => "postseason",
+            StandingsType::ByDivision => "byDivision",
+            StandingsType::ByConference => "byConference",
+            StandingsType::ByLeague => "byLeague",
+            StandingsType::ByOrganization => "byOrganization",
+        };
+        String::from(s)
+    }
+}
+
+/// Group of team records, `division` is only present when the records are
+/// grouped by division
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct StandingsRecord {
+    #[serde(default, deserialize_with = "fail_as_none")]
+    pub standings_type: Option<StandingsType>,
+    pub league: Option<StandingsRecordDetail>,
+    pub division: Option<StandingsRecordDetail>,
+    pub sport: Option<StandingsRecordDetail>,
+    pub last_updated: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub team_records: Vec<StandingsTeamRecord>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct StandingsRecordDetail {
+    pub id: u32,
+    #[serde(default)]
+    pub link: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct StandingsTeamRecord {
+    pub team: TeamDetail,
+    pub season: Option<String>,
+    pub streak: Option<Streak>,
+    /// "w" wild card, "y" division, "z" best league record
+    pub clinch_indicator: Option<String>,
+    #[serde(default, deserialize_with = "from_str_as_option")]
+    pub division_rank: Option<u16>,
+    #[serde(default, deserialize_with = "from_str_as_option")]
+    pub league_rank: Option<u16>,
+    #[serde(default, deserialize_with = "from_str_as_option")]
+    pub sport_rank: Option<u16>,
+    /// Only present for teams that aren't leading their division
+    #[serde(default, deserialize_with = "from_str_as_option")]
+    pub wild_card_rank: Option<u16>,
+    #[serde(default)]
+    pub games_played: u16,
+    /// `None` for the leader
+    #[serde(default, deserialize_with = "from_str_as_option")]
+    pub games_back: Option<f32>,
+    /// Negative values are games ahead of the last wild card spot
+    #[serde(default, deserialize_with = "from_str_as_option")]
+    pub wild_card_games_back: Option<f32>,
+    #[serde(default, deserialize_with = "from_str_as_option")]
+    pub league_games_back: Option<f32>,
+    #[serde(default, deserialize_with = "from_str_as_option")]
+    pub division_games_back: Option<f32>,
+    pub league_record: LeagueRecord,
+    #[serde(default)]
+    pub runs_allowed: u16,
+    #[serde(default)]
+    pub runs_scored: u16,
+    #[serde(default)]
+    pub run_differential: i16,
+    #[serde(default)]
+    pub division_champ: bool,
+    #[serde(default)]
+    pub division_leader: bool,
+    #[serde(default)]
+    pub has_wildcard: bool,
+    #[serde(default)]
+    pub clinched: bool,
+    /// Number such as "12", "-" when clinched or "E" when eliminated
+    pub elimination_number: Option<String>,
+    pub wild_card_elimination_number: Option<String>,
+    pub magic_number: Option<String>,
+    #[serde(default)]
+    pub wins: u16,
+    #[serde(default)]
+    pub losses: u16,
+    pub winning_percentage: Option<String>,
+    pub last_updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LeagueRecord {
+    #[serde(default)]
+    pub wins: u16,
+    #[serde(default)]
+    pub losses: u16,
+    pub ties: Option<u16>,
+    /// Winning percentage such as ".636"
+    pub pct: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Streak {
+    /// "wins" or "losses"
+    #[serde(default)]
+    pub streak_type: String,
+    #[serde(default)]
+    pub streak_number: u16,
+    /// Such as "W3"
+    #[serde(default)]
+    pub streak_code: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamDetail {
+    pub id: u32,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub link: String,
+}
+
+fn empty_as_none<'de, D, T>(de: D) -> Result<Option<T>, D::Error>
 where
     D: serde::Deserializer<'de>,
-    T: serde::Deserialize<'de>,
+    T: serde::de::DeserializeOwned,
 {
-    let result = T::deserialize(de);
-    match result {
-        Ok(t) => Ok(Some(t)),
-        Err(_) => Ok(None),
+    let value = serde_json::Value::deserialize(de)?;
+    match value {
+        serde_json::Value::Object(ref map) if map.is_empty() => Ok(None),
+        value => T::deserialize(value)
+            .map(Some)
+            .map_err(serde::de::Error::custom),
     }
 }
@@ -1,5 +1,14 @@
+use super::common::{
+    fail_as_none, from_str_as_option, ordinal_as_option, splits_or_empty, FromSplits, StatsVisitor,
+};
 use chrono::{DateTime, NaiveDate, Utc};
-use serde::{Deserialize, Serialize};
+use serde::{
+    de::{DeserializeSeed, Error as _, MapAccess, Visitor},
+    Deserialize, Serialize,
+};
+use std::{collections::HashMap, marker::PhantomData};
+
+pub use super::common::{AbstractGameState, DetailedState, GameStatus};
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -16,6 +25,7 @@ pub struct Team {
     pub name: String,
     #[serde(default)]
     pub link: String,
+    pub venue: Option<VenueDetail>,
     #[serde(default)]
     pub abbreviation: String,
     #[serde(default)]
@@ -23,12 +33,224 @@ pub struct Team {
     #[serde(default)]
     pub location_name: String,
     pub first_year_of_play: Option<String>,
+    /// Teams that haven't played yet have a division and conference without an id
+    pub division: Option<DivisionDetail>,
+    pub conference: Option<ConferenceDetail>,
+    pub franchise: Option<FranchiseDetail>,
     pub short_name: Option<String>,
     pub official_site_url: Option<String>,
     #[serde(default)]
     pub franchise_id: u32,
     #[serde(default)]
     pub active: bool,
+    /// Only present when requested with `get_team_with_roster`
+    pub roster: Option<RosterResponse>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct VenueDetail {
+    pub id: Option<u32>,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub link: String,
+    pub city: Option<String>,
+    pub time_zone: Option<TimeZone>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeZone {
+    /// Such as "America/New_York"
+    #[serde(default)]
+    pub id: String,
+    /// Current offset from UTC in hours
+    #[serde(default)]
+    pub offset: i8,
+    /// Such as "EDT"
+    #[serde(default)]
+    pub tz: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DivisionDetail {
+    pub id: Option<u32>,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub link: String,
+    pub name_short: Option<String>,
+    pub abbreviation: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConferenceDetail {
+    pub id: Option<u32>,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub link: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FranchiseDetail {
+    pub franchise_id: u32,
+    #[serde(default)]
+    pub team_name: String,
+    #[serde(default)]
+    pub link: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DivisionsResponse {
+    #[serde(default)]
+    pub divisions: Vec<Division>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Division {
+    pub id: u32,
+    #[serde(default)]
+    pub name: String,
+    pub name_short: Option<String>,
+    #[serde(default)]
+    pub link: String,
+    pub abbreviation: Option<String>,
+    pub conference: Option<ConferenceDetail>,
+    #[serde(default)]
+    pub active: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConferencesResponse {
+    #[serde(default)]
+    pub conferences: Vec<Conference>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Conference {
+    pub id: u32,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub link: String,
+    pub abbreviation: Option<String>,
+    pub short_name: Option<String>,
+    #[serde(default)]
+    pub active: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct VenuesResponse {
+    #[serde(default)]
+    pub venues: Vec<Venue>,
+}
+
+/// The NHL API only lists venue names, a team's `venue` also carries its
+/// city and time zone
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Venue {
+    pub id: u32,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub link: String,
+    pub app_enabled: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SeasonsResponse {
+    #[serde(default)]
+    pub seasons: Vec<Season>,
+}
+
+/// Key dates of a season, the NHL API doesn't list playoff or all-star dates
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Season {
+    /// Such as "20192020", used for the `season` of queries
+    pub season_id: String,
+    pub regular_season_start_date: NaiveDate,
+    pub regular_season_end_date: NaiveDate,
+    /// Last day of the playoffs
+    pub season_end_date: NaiveDate,
+    /// Regular season games per team
+    #[serde(default)]
+    pub number_of_games: u16,
+    #[serde(default)]
+    pub ties_in_use: bool,
+    #[serde(default)]
+    pub olympics_participation: bool,
+    #[serde(default)]
+    pub conferences_in_use: bool,
+    #[serde(default)]
+    pub divisions_in_use: bool,
+    #[serde(default)]
+    pub wild_card_in_use: bool,
+}
+
+impl Season {
+    /// Season `date` belongs to, the first one that hasn't ended by then
+    ///
+    /// The API has no preseason dates, so a date between seasons counts
+    /// towards the next season once it's listed, or else the last one. Dates
+    /// before the first regular season don't belong to any season.
+    pub fn for_date(seasons: &[Season], date: NaiveDate) -> Option<&Season> {
+        let first = seasons
+            .iter()
+            .map(|season| season.regular_season_start_date)
+            .min()?;
+        if date < first {
+            return None;
+        }
+
+        seasons
+            .iter()
+            .filter(|season| date <= season.season_end_date)
+            .min_by_key(|season| season.season_end_date)
+            .or_else(|| seasons.iter().max_by_key(|season| season.season_end_date))
+    }
+
+    /// Whether `date` falls between the start of the regular season and the
+    /// end of the playoffs
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.regular_season_start_date <= date && date <= self.season_end_date
+    }
+
+    /// Whether `date` falls after the regular season, up to the end of the
+    /// playoffs
+    pub fn is_postseason(&self, date: NaiveDate) -> bool {
+        self.regular_season_end_date < date && date <= self.season_end_date
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RosterResponse {
+    #[serde(default)]
+    pub roster: Vec<RosterEntry>,
+    #[serde(default)]
+    pub link: String,
+}
+
+/// The NHL roster doesn't carry a player status, see `Person::roster_status` instead
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RosterEntry {
+    pub person: PersonDetail,
+    pub jersey_number: Option<String>,
+    pub position: Position,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -38,6 +260,88 @@ pub struct ScheduleResponse {
     pub dates: Vec<Schedule>,
 }
 
+/// Parameters for `get_schedule`, every filter is optional
+#[derive(Debug, Clone, Default)]
+pub struct ScheduleQuery {
+    pub start_date: Option<NaiveDate>,
+    pub end_date: Option<NaiveDate>,
+    pub team_id: Option<u32>,
+    pub opponent_id: Option<u32>,
+    /// Such as "R" for regular season, "PR" for preseason or "P" for playoffs
+    pub game_type: Option<String>,
+    /// Such as "20192020"
+    pub season: Option<String>,
+    pub expands: Vec<ScheduleExpand>,
+}
+
+impl ScheduleQuery {
+    pub fn new() -> Self {
+        ScheduleQuery::default()
+    }
+
+    /// Games on a single day
+    pub fn date(self, date: NaiveDate) -> Self {
+        self.date_range(date, date)
+    }
+
+    /// Games between `start` and `end`, inclusive
+    pub fn date_range(mut self, start: NaiveDate, end: NaiveDate) -> Self {
+        self.start_date = Some(start);
+        self.end_date = Some(end);
+        self
+    }
+
+    pub fn team(mut self, team_id: u32) -> Self {
+        self.team_id = Some(team_id);
+        self
+    }
+
+    pub fn opponent(mut self, opponent_id: u32) -> Self {
+        self.opponent_id = Some(opponent_id);
+        self
+    }
+
+    pub fn game_type(mut self, game_type: &str) -> Self {
+        self.game_type = Some(game_type.to_owned());
+        self
+    }
+
+    pub fn season(mut self, season: &str) -> Self {
+        self.season = Some(season.to_owned());
+        self
+    }
+
+    /// Populate the optional `ScheduleGame` fields for `expand`
+    pub fn expand(mut self, expand: ScheduleExpand) -> Self {
+        if !self.expands.contains(&expand) {
+            self.expands.push(expand);
+        }
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScheduleExpand {
+    Linescore,
+    Broadcasts,
+    Teams,
+    Venue,
+    Tickets,
+}
+
+impl From<&ScheduleExpand> for String {
+    fn from(expand: &ScheduleExpand) -> String {
+        let s = match expand {
+            ScheduleExpand::Linescore => "schedule.linescore",
+            ScheduleExpand::Broadcasts => "schedule.broadcasts.all",
+            ScheduleExpand::Teams => "schedule.teams",
+            ScheduleExpand::Venue => "schedule.venue",
+            ScheduleExpand::Tickets => "schedule.ticket",
+        };
+        String::from(s)
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Schedule {
@@ -56,9 +360,30 @@ pub struct ScheduleGame {
     pub date: DateTime<Utc>,
     #[serde(default)]
     pub game_type: String,
+    /// Season id such as "20192020", schedules don't embed the season itself so
+    /// use `season_of` with the seasons from `get_seasons` to get its dates
     #[serde(default)]
     pub season: String,
+    pub status: GameStatus,
     pub teams: ScheduleGameTeams,
+    pub venue: Option<ScheduleGameVenue>,
+    /// Only present with `ScheduleExpand::Linescore`
+    pub linescore: Option<GameLinescoreResponse>,
+    /// Only present with `ScheduleExpand::Broadcasts`
+    #[serde(default)]
+    pub broadcasts: Vec<ScheduleGameBroadcast>,
+    /// Only present with `ScheduleExpand::Tickets`
+    #[serde(default)]
+    pub tickets: Vec<ScheduleGameTicket>,
+}
+
+impl ScheduleGame {
+    /// Finds the game's season among `seasons`, such as from `get_seasons`
+    pub fn season_of<'a>(&self, seasons: &'a [Season]) -> Option<&'a Season> {
+        seasons
+            .iter()
+            .find(|season| season.season_id == self.season)
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -74,6 +399,8 @@ pub struct ScheduleGameTeam {
     pub score: Option<u8>,
     #[serde(rename(deserialize = "team"))]
     pub detail: ScheduleGameTeamDetail,
+    /// Record going into the game, or after it once final
+    pub league_record: Option<LeagueRecord>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -84,6 +411,43 @@ pub struct ScheduleGameTeamDetail {
     pub name: String,
     #[serde(default)]
     pub link: String,
+    /// Only present with `ScheduleExpand::Teams`
+    pub abbreviation: Option<String>,
+    pub team_name: Option<String>,
+    pub location_name: Option<String>,
+    pub short_name: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleGameVenue {
+    pub id: Option<u32>,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub link: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleGameBroadcast {
+    pub id: u32,
+    #[serde(default)]
+    pub name: String,
+    /// "national", "home" or "away"
+    #[serde(default)]
+    pub r#type: String,
+    pub site: Option<String>,
+    pub language: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleGameTicket {
+    /// Such as "tickets", "buysell" or "mobile"
+    #[serde(default)]
+    pub ticket_type: String,
+    pub ticket_link: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -272,7 +636,83 @@ pub struct GameContentMilestoneItemHighlightPlayback {
 pub struct GameLinescoreResponse {
     #[serde(default)]
     pub current_period: u8,
+    pub current_period_ordinal: Option<String>,
+    /// Time remaining such as "12:34", or "END" / "Final"
+    pub current_period_time_remaining: Option<String>,
+    #[serde(default)]
+    pub periods: Vec<GameLinescorePeriod>,
+    #[serde(default)]
+    pub has_shootout: bool,
+    pub shootout_info: Option<GameLinescoreShootoutInfo>,
     pub teams: GameLinescoreTeams,
+    pub power_play_strength: Option<String>,
+    pub power_play_info: Option<GameLinescorePowerPlayInfo>,
+    pub intermission_info: Option<GameLinescoreIntermissionInfo>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameLinescorePeriod {
+    #[serde(default)]
+    pub period_type: String,
+    pub start_time: Option<DateTime<Utc>>,
+    pub end_time: Option<DateTime<Utc>>,
+    pub num: u8,
+    #[serde(default)]
+    pub ordinal_num: String,
+    pub home: GameLinescorePeriodTeam,
+    pub away: GameLinescorePeriodTeam,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameLinescorePeriodTeam {
+    #[serde(default)]
+    pub goals: u8,
+    #[serde(default)]
+    pub shots_on_goal: u8,
+    pub rink_side: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameLinescoreShootoutInfo {
+    pub home: GameLinescoreShootoutTeam,
+    pub away: GameLinescoreShootoutTeam,
+    pub start_time: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameLinescoreShootoutTeam {
+    #[serde(default)]
+    pub scores: u8,
+    #[serde(default)]
+    pub attempts: u8,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameLinescorePowerPlayInfo {
+    /// Seconds remaining in the current power play
+    #[serde(default)]
+    pub situation_time_remaining: u32,
+    #[serde(default)]
+    pub situation_time_elapsed: u32,
+    #[serde(default)]
+    pub in_situation: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameLinescoreIntermissionInfo {
+    /// Seconds remaining in the current intermission
+    #[serde(default)]
+    pub intermission_time_remaining: u32,
+    #[serde(default)]
+    pub intermission_time_elapsed: u32,
+    #[serde(default)]
+    pub in_intermission: bool,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -289,6 +729,14 @@ pub struct GameLinescoreTeam {
     pub detail: GameLinescoreTeamDetail,
     #[serde(default)]
     pub goals: u8,
+    #[serde(default)]
+    pub shots_on_goal: u8,
+    #[serde(default)]
+    pub goalie_pulled: bool,
+    #[serde(default)]
+    pub num_skaters: u8,
+    #[serde(default)]
+    pub power_play: bool,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -302,14 +750,1237 @@ pub struct GameLinescoreTeamDetail {
     pub link: String,
 }
 
-fn fail_as_none<'de, D, T>(de: D) -> Result<Option<T>, D::Error>
-where
-    D: serde::Deserializer<'de>,
-    T: serde::Deserialize<'de>,
-{
-    let result = T::deserialize(de);
-    match result {
-        Ok(t) => Ok(Some(t)),
-        Err(_) => Ok(None),
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedResponse {
+    pub game_pk: u64,
+    #[serde(default)]
+    pub link: String,
+    pub meta_data: Option<GameFeedMetaData>,
+    pub game_data: GameFeedGameData,
+    pub live_data: GameFeedLiveData,
+}
+
+/// Change between two versions of a live game feed, see `NhlClient::watch_game`
+#[derive(Debug, Clone)]
+pub enum GameEvent {
+    /// Abstract or detailed state changed, such as the game going final
+    Status(GameStatus),
+    Score {
+        away: u8,
+        home: u8,
+    },
+    Period {
+        period: u8,
+    },
+    /// Play such as a goal, shot or penalty
+    Play(Box<GameFeedPlay>),
+}
+
+impl GameFeedResponse {
+    /// Events describing what changed from `previous` to this version of the feed
+    pub fn events_since(&self, previous: &GameFeedResponse) -> Vec<GameEvent> {
+        let mut events = vec![];
+
+        let plays = &self.live_data.plays.all_plays;
+        let previous_plays = previous.live_data.plays.all_plays.len();
+        for play in plays.iter().skip(previous_plays) {
+            events.push(GameEvent::Play(Box::new(play.clone())));
+        }
+
+        let teams = &self.live_data.linescore.teams;
+        let previous_teams = &previous.live_data.linescore.teams;
+        if teams.away.goals != previous_teams.away.goals
+            || teams.home.goals != previous_teams.home.goals
+        {
+            events.push(GameEvent::Score {
+                away: teams.away.goals,
+                home: teams.home.goals,
+            });
+        }
+
+        let period = self.live_data.linescore.current_period;
+        if period != previous.live_data.linescore.current_period {
+            events.push(GameEvent::Period { period });
+        }
+
+        let status = &self.game_data.status;
+        let previous_status = &previous.game_data.status;
+        if status.abstract_game_state != previous_status.abstract_game_state
+            || status.detailed_state != previous_status.detailed_state
+        {
+            events.push(GameEvent::Status(status.clone()));
+        }
+
+        events
+    }
+}
+
+/// Live feed kept alongside its raw JSON, so it can be refreshed from the
+/// `diffPatch` operations of `NhlClient::update_game_feed`
+#[derive(Debug, Clone)]
+pub struct LiveGameFeed {
+    pub(crate) feed: GameFeedResponse,
+    pub(crate) raw: serde_json::Value,
+}
+
+impl LiveGameFeed {
+    pub fn feed(&self) -> &GameFeedResponse {
+        &self.feed
+    }
+
+    pub fn into_feed(self) -> GameFeedResponse {
+        self.feed
+    }
+
+    /// Timecode of this version of the feed, diffs are requested from it
+    pub fn time_stamp(&self) -> Option<&str> {
+        self.feed
+            .meta_data
+            .as_ref()
+            .map(|meta_data| meta_data.time_stamp.as_str())
+            .filter(|time_stamp| !time_stamp.is_empty())
     }
 }
+
+/// One batch of JSON patch operations from `feed/live/diffPatch`
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedDiff {
+    pub diff: json_patch::Patch,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedMetaData {
+    /// Suggested number of seconds to wait before polling again
+    #[serde(default)]
+    pub wait: u32,
+    /// Timecode of this version of the feed, formatted as `yyyymmdd_hhmmss`
+    #[serde(default)]
+    pub time_stamp: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedGameData {
+    pub game: GameFeedGame,
+    pub datetime: GameFeedDateTime,
+    pub status: GameStatus,
+    pub teams: GameFeedTeams,
+    /// Players dressed for the game, keyed by `ID{player_id}`
+    #[serde(default)]
+    pub players: HashMap<String, Person>,
+    pub venue: Option<GameFeedVenue>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedGame {
+    pub pk: u64,
+    #[serde(default)]
+    pub season: String,
+    #[serde(default)]
+    pub r#type: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedDateTime {
+    pub date_time: DateTime<Utc>,
+    pub end_date_time: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedTeams {
+    pub away: Team,
+    pub home: Team,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedVenue {
+    pub id: Option<u32>,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub link: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PeopleResponse {
+    #[serde(default)]
+    pub people: Vec<Person>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Person {
+    pub id: u32,
+    #[serde(default)]
+    pub full_name: String,
+    #[serde(default)]
+    pub link: String,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub primary_number: Option<String>,
+    pub birth_date: Option<NaiveDate>,
+    pub current_age: Option<u8>,
+    pub birth_city: Option<String>,
+    pub birth_state_province: Option<String>,
+    pub birth_country: Option<String>,
+    pub nationality: Option<String>,
+    /// Height such as `6' 2"`
+    pub height: Option<String>,
+    /// Weight in pounds
+    pub weight: Option<u16>,
+    #[serde(default)]
+    pub active: bool,
+    #[serde(default)]
+    pub alternate_captain: bool,
+    #[serde(default)]
+    pub captain: bool,
+    #[serde(default)]
+    pub rookie: bool,
+    pub shoots_catches: Option<String>,
+    pub roster_status: Option<String>,
+    pub current_team: Option<TeamDetail>,
+    pub primary_position: Option<Position>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonDetail {
+    #[serde(default)]
+    pub id: u32,
+    #[serde(default)]
+    pub full_name: String,
+    #[serde(default)]
+    pub link: String,
+}
+
+impl Person {
+    /// 168x168 headshot image
+    pub fn headshot_url(&self) -> String {
+        headshot_url(self.id)
+    }
+}
+
+impl PersonDetail {
+    /// 168x168 headshot image
+    pub fn headshot_url(&self) -> String {
+        headshot_url(self.id)
+    }
+}
+
+fn headshot_url(person_id: u32) -> String {
+    format!(
+        "https://cms.nhl.bamgrid.com/images/headshots/current/168x168/{}.jpg",
+        person_id
+    )
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamDetail {
+    pub id: u32,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub link: String,
+    pub abbreviation: Option<String>,
+    pub tri_code: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Position {
+    #[serde(default)]
+    pub code: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub r#type: String,
+    #[serde(default)]
+    pub abbreviation: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedLiveData {
+    pub plays: GameFeedPlays,
+    pub linescore: GameLinescoreResponse,
+    pub boxscore: GameBoxscoreResponse,
+    #[serde(default)]
+    pub decisions: GameFeedDecisions,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedPlays {
+    #[serde(default)]
+    pub all_plays: Vec<GameFeedPlay>,
+    /// Indices into `all_plays`
+    #[serde(default)]
+    pub scoring_plays: Vec<usize>,
+    /// Indices into `all_plays`
+    #[serde(default)]
+    pub penalty_plays: Vec<usize>,
+    #[serde(default)]
+    pub plays_by_period: Vec<GameFeedPlaysByPeriod>,
+    pub current_play: Option<GameFeedPlay>,
+}
+
+impl GameFeedPlays {
+    pub fn scoring(&self) -> impl Iterator<Item = &GameFeedPlay> {
+        self.scoring_plays
+            .iter()
+            .filter_map(move |idx| self.all_plays.get(*idx))
+    }
+
+    pub fn penalties(&self) -> impl Iterator<Item = &GameFeedPlay> {
+        self.penalty_plays
+            .iter()
+            .filter_map(move |idx| self.all_plays.get(*idx))
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedPlaysByPeriod {
+    #[serde(default)]
+    pub start_index: usize,
+    #[serde(default)]
+    pub plays: Vec<usize>,
+    #[serde(default)]
+    pub end_index: usize,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedPlay {
+    #[serde(default)]
+    pub players: Vec<GameFeedPlayPlayer>,
+    pub result: GameFeedPlayResult,
+    pub about: GameFeedPlayAbout,
+    #[serde(default)]
+    pub coordinates: GameFeedPlayCoordinates,
+    pub team: Option<TeamDetail>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedPlayPlayer {
+    pub player: PersonDetail,
+    /// Role in the play such as "Scorer", "Assist", "Goalie" or "Hitter"
+    #[serde(default)]
+    pub player_type: String,
+    pub season_total: Option<u32>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedPlayResult {
+    #[serde(default)]
+    pub event: String,
+    #[serde(default)]
+    pub event_code: String,
+    pub event_type_id: EventType,
+    #[serde(default)]
+    pub description: String,
+    pub secondary_type: Option<String>,
+    pub strength: Option<GameFeedPlayStrength>,
+    pub game_winning_goal: Option<bool>,
+    pub empty_net: Option<bool>,
+    pub penalty_severity: Option<String>,
+    pub penalty_minutes: Option<u8>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum EventType {
+    GameScheduled,
+    PeriodReady,
+    PeriodStart,
+    Faceoff,
+    Shot,
+    MissedShot,
+    BlockedShot,
+    Goal,
+    Hit,
+    Giveaway,
+    Takeaway,
+    Penalty,
+    Stop,
+    Challenge,
+    PeriodEnd,
+    PeriodOfficial,
+    ShootoutComplete,
+    GameEnd,
+    GameOfficial,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedPlayStrength {
+    #[serde(default)]
+    pub code: String,
+    #[serde(default)]
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedPlayAbout {
+    pub event_idx: usize,
+    #[serde(default)]
+    pub event_id: u32,
+    #[serde(default)]
+    pub period: u8,
+    #[serde(default)]
+    pub period_type: String,
+    #[serde(default)]
+    pub ordinal_num: String,
+    #[serde(default)]
+    pub period_time: String,
+    #[serde(default)]
+    pub period_time_remaining: String,
+    pub date_time: DateTime<Utc>,
+    pub goals: GameFeedPlayGoals,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedPlayGoals {
+    #[serde(default)]
+    pub away: u8,
+    #[serde(default)]
+    pub home: u8,
+}
+
+/// Rink coordinates in feet from center ice, empty for events without a location
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedPlayCoordinates {
+    pub x: Option<f32>,
+    pub y: Option<f32>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GameFeedDecisions {
+    pub winner: Option<PersonDetail>,
+    pub loser: Option<PersonDetail>,
+    pub first_star: Option<PersonDetail>,
+    pub second_star: Option<PersonDetail>,
+    pub third_star: Option<PersonDetail>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameBoxscoreResponse {
+    pub teams: GameBoxscoreTeams,
+    #[serde(default)]
+    pub officials: Vec<GameBoxscoreOfficial>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameBoxscoreTeams {
+    pub away: GameBoxscoreTeam,
+    pub home: GameBoxscoreTeam,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameBoxscoreTeam {
+    #[serde(rename(deserialize = "team"))]
+    pub detail: TeamDetail,
+    #[serde(default)]
+    pub team_stats: GameBoxscoreTeamStats,
+    /// Players keyed by `ID{player_id}`
+    #[serde(default)]
+    pub players: HashMap<String, GameBoxscorePlayer>,
+    #[serde(default)]
+    pub goalies: Vec<u32>,
+    #[serde(default)]
+    pub skaters: Vec<u32>,
+    #[serde(default)]
+    pub on_ice: Vec<u32>,
+    #[serde(default)]
+    pub scratches: Vec<u32>,
+    #[serde(default)]
+    pub penalty_box: Vec<GameBoxscorePenaltyBox>,
+    #[serde(default)]
+    pub coaches: Vec<GameBoxscoreCoach>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GameBoxscoreTeamStats {
+    pub team_skater_stats: Option<GameBoxscoreTeamSkaterStats>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameBoxscoreTeamSkaterStats {
+    #[serde(default)]
+    pub goals: u8,
+    #[serde(default)]
+    pub pim: u16,
+    #[serde(default)]
+    pub shots: u8,
+    #[serde(default)]
+    pub power_play_percentage: String,
+    #[serde(default)]
+    pub power_play_goals: f32,
+    #[serde(default)]
+    pub power_play_opportunities: f32,
+    #[serde(default)]
+    pub face_off_win_percentage: String,
+    #[serde(default)]
+    pub blocked: u8,
+    #[serde(default)]
+    pub takeaways: u8,
+    #[serde(default)]
+    pub giveaways: u8,
+    #[serde(default)]
+    pub hits: u8,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameBoxscorePlayer {
+    pub person: PersonDetail,
+    pub jersey_number: Option<String>,
+    pub position: Option<Position>,
+    #[serde(default)]
+    pub stats: GameBoxscorePlayerStats,
+}
+
+/// Only one of the two is present, scratched players have neither
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GameBoxscorePlayerStats {
+    pub skater_stats: Option<GameBoxscoreSkaterStats>,
+    pub goalie_stats: Option<GameBoxscoreGoalieStats>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameBoxscoreSkaterStats {
+    /// Time on ice formatted as `mm:ss`
+    #[serde(default)]
+    pub time_on_ice: String,
+    #[serde(default)]
+    pub assists: u8,
+    #[serde(default)]
+    pub goals: u8,
+    #[serde(default)]
+    pub shots: u8,
+    #[serde(default)]
+    pub hits: u8,
+    #[serde(default)]
+    pub power_play_goals: u8,
+    #[serde(default)]
+    pub power_play_assists: u8,
+    #[serde(default)]
+    pub penalty_minutes: u16,
+    pub face_off_pct: Option<f32>,
+    #[serde(default)]
+    pub face_off_wins: u8,
+    #[serde(default, rename = "faceoffTaken")]
+    pub face_off_taken: u8,
+    #[serde(default)]
+    pub takeaways: u8,
+    #[serde(default)]
+    pub giveaways: u8,
+    #[serde(default)]
+    pub short_handed_goals: u8,
+    #[serde(default)]
+    pub short_handed_assists: u8,
+    #[serde(default)]
+    pub blocked: u8,
+    #[serde(default)]
+    pub plus_minus: i8,
+    #[serde(default)]
+    pub even_time_on_ice: String,
+    #[serde(default)]
+    pub power_play_time_on_ice: String,
+    #[serde(default)]
+    pub short_handed_time_on_ice: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameBoxscoreGoalieStats {
+    /// Time on ice formatted as `mm:ss`
+    #[serde(default)]
+    pub time_on_ice: String,
+    #[serde(default)]
+    pub assists: u8,
+    #[serde(default)]
+    pub goals: u8,
+    #[serde(default)]
+    pub pim: u16,
+    #[serde(default)]
+    pub shots: u8,
+    #[serde(default)]
+    pub saves: u8,
+    #[serde(default)]
+    pub power_play_saves: u8,
+    #[serde(default)]
+    pub short_handed_saves: u8,
+    #[serde(default)]
+    pub even_saves: u8,
+    #[serde(default)]
+    pub short_handed_shots_against: u8,
+    #[serde(default)]
+    pub even_shots_against: u8,
+    #[serde(default)]
+    pub power_play_shots_against: u8,
+    /// "W" or "L" for the goalie of record
+    pub decision: Option<String>,
+    pub save_percentage: Option<f32>,
+    pub power_play_save_percentage: Option<f32>,
+    pub short_handed_save_percentage: Option<f32>,
+    pub even_strength_save_percentage: Option<f32>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameBoxscorePenaltyBox {
+    pub id: u32,
+    #[serde(default)]
+    pub time_remaining: String,
+    #[serde(default)]
+    pub active: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameBoxscoreCoach {
+    pub person: PersonDetail,
+    pub position: Position,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameBoxscoreOfficial {
+    pub official: PersonDetail,
+    #[serde(default)]
+    pub official_type: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct StandingsResponse {
+    #[serde(default)]
+    pub records: Vec<StandingsRecord>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum StandingsType {
+    RegularSeason,
+    WildCard,
+    DivisionLeaders,
+    WildCardWithLeaders,
+    Preseason,
+    Postseason,
+    ByDivision,
+    ByConference,
+    ByLeague,
+}
+
+impl From<&StandingsType> for String {
+    fn from(standings_type: &StandingsType) -> String {
+        let s = match standings_type {
+            StandingsType::RegularSeason => "regularSeason",
+            StandingsType::WildCard => "wildCard",
+            StandingsType::DivisionLeaders => "divisionLeaders",
+            StandingsType::WildCardWithLeaders => "wildCardWithLeaders",
+            StandingsType::Preseason => "preseason",
+            StandingsType::Postseason => "postseason",
+            StandingsType::ByDivision => "byDivision",
+            StandingsType::ByConference => "byConference",
+            StandingsType::ByLeague => "byLeague",
+        };
+        String::from(s)
+    }
+}
+
+/// Group of team records, `division` and `conference` are only present
+/// when the records are grouped by them
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct StandingsRecord {
+    #[serde(default, deserialize_with = "fail_as_none")]
+    pub standings_type: Option<StandingsType>,
+    pub league: Option<StandingsRecordDetail>,
+    pub division: Option<StandingsRecordDetail>,
+    pub conference: Option<StandingsRecordDetail>,
+    pub season: Option<String>,
+    #[serde(default)]
+    pub team_records: Vec<StandingsTeamRecord>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct StandingsRecordDetail {
+    pub id: u32,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub link: String,
+    pub abbreviation: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct StandingsTeamRecord {
+    pub team: TeamDetail,
+    pub league_record: LeagueRecord,
+    #[serde(default)]
+    pub regulation_wins: u16,
+    #[serde(default)]
+    pub goals_against: u16,
+    #[serde(default)]
+    pub goals_scored: u16,
+    #[serde(default)]
+    pub points: u16,
+    #[serde(default, deserialize_with = "from_str_as_option")]
+    pub division_rank: Option<u16>,
+    #[serde(default, deserialize_with = "from_str_as_option")]
+    pub conference_rank: Option<u16>,
+    #[serde(default, deserialize_with = "from_str_as_option")]
+    pub league_rank: Option<u16>,
+    /// Rank within the conference wild card race, `0` for division leaders
+    #[serde(default, deserialize_with = "from_str_as_option")]
+    pub wild_card_rank: Option<u16>,
+    /// Regulation plus overtime wins
+    #[serde(default)]
+    pub row: u16,
+    #[serde(default)]
+    pub games_played: u16,
+    pub streak: Option<Streak>,
+    /// "x" clinched playoff berth, "y" division, "z" conference, "p" Presidents' Trophy,
+    /// "e" eliminated
+    pub clinch_indicator: Option<String>,
+    pub points_percentage: Option<f32>,
+    pub last_updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LeagueRecord {
+    #[serde(default)]
+    pub wins: u16,
+    #[serde(default)]
+    pub losses: u16,
+    #[serde(default)]
+    pub ot: u16,
+    pub r#type: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Streak {
+    /// "wins", "losses" or "ot"
+    #[serde(default)]
+    pub streak_type: String,
+    #[serde(default)]
+    pub streak_number: u16,
+    /// Such as "W3"
+    #[serde(default)]
+    pub streak_code: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonStatsResponse {
+    #[serde(default)]
+    pub stats: Vec<PersonStats>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum StatsType {
+    #[serde(rename = "statsSingleSeason")]
+    Season,
+    #[serde(rename = "careerRegularSeason")]
+    Career,
+    YearByYear,
+    GameLog,
+    VsTeam,
+    HomeAndAway,
+    ByMonth,
+    ByDayOfWeek,
+}
+
+impl From<&StatsType> for String {
+    fn from(stats_type: &StatsType) -> String {
+        let s = match stats_type {
+            StatsType::Season => "statsSingleSeason",
+            StatsType::Career => "careerRegularSeason",
+            StatsType::YearByYear => "yearByYear",
+            StatsType::GameLog => "gameLog",
+            StatsType::VsTeam => "vsTeam",
+            StatsType::HomeAndAway => "homeAndAway",
+            StatsType::ByMonth => "byMonth",
+            StatsType::ByDayOfWeek => "byDayOfWeek",
+        };
+        String::from(s)
+    }
+}
+
+/// Parameters for `get_person_stats`
+///
+/// Defaults to the current season when `season`, such as "20192020", isn't set
+#[derive(Debug, Clone)]
+pub struct StatsQuery {
+    pub stats: StatsType,
+    pub season: Option<String>,
+}
+
+impl StatsQuery {
+    pub fn new(stats: StatsType) -> Self {
+        StatsQuery {
+            stats,
+            season: None,
+        }
+    }
+
+    pub fn season(mut self, season: &str) -> Self {
+        self.season = Some(season.to_owned());
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonStats {
+    pub r#type: PersonStatsType,
+    #[serde(default)]
+    pub splits: Vec<StatsSplit>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonStatsType {
+    #[serde(default, deserialize_with = "fail_as_none")]
+    pub display_name: Option<StatsType>,
+}
+
+/// One stat line. Which of the optional fields are present depends on the
+/// stat type, e.g. `date` and `game` for game logs, `month` for by month.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsSplit {
+    pub season: Option<String>,
+    pub stat: PlayerStats,
+    pub team: Option<TeamDetail>,
+    pub opponent: Option<TeamDetail>,
+    pub opponent_division: Option<TeamDetail>,
+    pub opponent_conference: Option<TeamDetail>,
+    pub date: Option<NaiveDate>,
+    pub game: Option<StatsSplitGame>,
+    pub is_home: Option<bool>,
+    pub is_win: Option<bool>,
+    #[serde(rename = "isOT")]
+    pub is_ot: Option<bool>,
+    pub month: Option<u8>,
+    /// 1 for Sunday through 7 for Saturday
+    pub day_of_week: Option<u8>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsSplitGame {
+    pub game_pk: u64,
+}
+
+/// Goalie lines are told apart from skater lines by their `saves`, since
+/// stat types such as "yearByYear" are shared by both
+#[derive(Debug, Serialize, Clone)]
+#[serde(untagged)]
+pub enum PlayerStats {
+    Goalie(GoalieStats),
+    Skater(SkaterStats),
+}
+
+impl<'de> Deserialize<'de> for PlayerStats {
+    fn deserialize<D>(de: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        de.deserialize_map(PlayerStatsVisitor)
+    }
+}
+
+/// Reads every stat in place through `StatSeed` before dispatching on `saves`,
+/// which keeps the path to a failing stat in `Error::Json`
+struct PlayerStatsVisitor;
+
+impl<'de> Visitor<'de> for PlayerStatsVisitor {
+    type Value = PlayerStats;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("a skater or goalie stat line")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<PlayerStats, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut stat = serde_json::Map::new();
+
+        while let Some(key) = map.next_key::<String>()? {
+            let value = map.next_value_seed(StatSeed { key: &key })?;
+            stat.insert(key, value);
+        }
+
+        let stats = if stat.contains_key("saves") {
+            serde_json::from_value(serde_json::Value::Object(stat)).map(PlayerStats::Goalie)
+        } else {
+            serde_json::from_value(serde_json::Value::Object(stat)).map(PlayerStats::Skater)
+        };
+
+        stats.map_err(A::Error::custom)
+    }
+}
+
+/// Checks one stat against both stat lines, so a bad value fails while its
+/// key is still being read
+struct StatSeed<'a> {
+    key: &'a str,
+}
+
+impl<'de> DeserializeSeed<'de> for StatSeed<'_> {
+    type Value = serde_json::Value;
+
+    fn deserialize<D>(self, de: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(de)?;
+
+        let skater = serde_json::json!({ self.key: value });
+        SkaterStats::deserialize(skater).map_err(D::Error::custom)?;
+
+        // `saves` is required, fill it in unless it's the stat being checked
+        let mut goalie = serde_json::json!({ "saves": 0 });
+        goalie[self.key] = value.clone();
+        GoalieStats::deserialize(goalie).map_err(D::Error::custom)?;
+
+        Ok(value)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SkaterStats {
+    pub games: u32,
+    pub goals: u32,
+    pub assists: u32,
+    pub points: u32,
+    pub plus_minus: i32,
+    pub pim: u32,
+    pub shots: u32,
+    pub hits: u32,
+    pub blocked: u32,
+    pub shifts: u32,
+    pub power_play_goals: u32,
+    pub power_play_points: u32,
+    pub short_handed_goals: u32,
+    pub short_handed_points: u32,
+    pub game_winning_goals: u32,
+    pub over_time_goals: u32,
+    /// Percentages such as 51.95
+    pub face_off_pct: Option<f32>,
+    pub shot_pct: Option<f32>,
+    /// Times such as "1625:58"
+    pub time_on_ice: Option<String>,
+    pub even_time_on_ice: Option<String>,
+    pub power_play_time_on_ice: Option<String>,
+    pub short_handed_time_on_ice: Option<String>,
+    pub time_on_ice_per_game: Option<String>,
+    pub even_time_on_ice_per_game: Option<String>,
+    pub power_play_time_on_ice_per_game: Option<String>,
+    pub short_handed_time_on_ice_per_game: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GoalieStats {
+    pub saves: u32,
+    #[serde(default)]
+    pub games: u32,
+    #[serde(default)]
+    pub games_started: u32,
+    #[serde(default)]
+    pub wins: u32,
+    #[serde(default)]
+    pub losses: u32,
+    #[serde(default)]
+    pub ot: u32,
+    #[serde(default)]
+    pub ties: u32,
+    #[serde(default)]
+    pub shutouts: u32,
+    #[serde(default)]
+    pub shots_against: u32,
+    #[serde(default)]
+    pub goals_against: u32,
+    #[serde(default)]
+    pub even_saves: u32,
+    #[serde(default)]
+    pub power_play_saves: u32,
+    #[serde(default)]
+    pub short_handed_saves: u32,
+    #[serde(default)]
+    pub even_shots: u32,
+    #[serde(default)]
+    pub power_play_shots: u32,
+    #[serde(default)]
+    pub short_handed_shots: u32,
+    /// Game log decision, "W" or "L"
+    pub decision: Option<String>,
+    /// Fraction such as 0.935484
+    pub save_percentage: Option<f32>,
+    pub goal_against_average: Option<f32>,
+    /// Percentages such as 93.5
+    pub even_strength_save_percentage: Option<f32>,
+    pub power_play_save_percentage: Option<f32>,
+    pub short_handed_save_percentage: Option<f32>,
+    pub time_on_ice: Option<String>,
+    pub time_on_ice_per_game: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamStatsResponse {
+    #[serde(default)]
+    pub stats: Vec<TeamStats>,
+}
+
+/// Team season totals or their ranks among all teams
+#[derive(Debug, Serialize, Clone)]
+pub enum TeamStats {
+    Season(Vec<TeamStatsSplit<TeamSeasonStats>>),
+    Rankings(Vec<TeamStatsSplit<TeamStatRankings>>),
+    /// Types without a typed stat line with their raw splits
+    Other {
+        stats_type: String,
+        splits: Vec<serde_json::Value>,
+    },
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamStatsSplit<T> {
+    pub stat: T,
+    pub team: Option<TeamDetail>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamSeasonStats {
+    #[serde(default)]
+    pub games_played: u16,
+    #[serde(default)]
+    pub wins: u16,
+    #[serde(default)]
+    pub losses: u16,
+    #[serde(default)]
+    pub ot: u16,
+    #[serde(default)]
+    pub pts: u16,
+    /// Percentages such as 61.0, sent as strings
+    #[serde(default, deserialize_with = "from_str_as_option")]
+    pub pt_pctg: Option<f32>,
+    #[serde(default, deserialize_with = "from_str_as_option")]
+    pub power_play_percentage: Option<f32>,
+    #[serde(default, deserialize_with = "from_str_as_option")]
+    pub penalty_kill_percentage: Option<f32>,
+    #[serde(default, deserialize_with = "from_str_as_option")]
+    pub face_off_win_percentage: Option<f32>,
+    pub goals_per_game: Option<f32>,
+    pub goals_against_per_game: Option<f32>,
+    /// Even strength goals for per goal against
+    #[serde(rename = "evGGARatio")]
+    pub ev_gga_ratio: Option<f32>,
+    pub power_play_goals: Option<f32>,
+    pub power_play_goals_against: Option<f32>,
+    pub power_play_opportunities: Option<f32>,
+    pub shots_per_game: Option<f32>,
+    pub shots_allowed: Option<f32>,
+    /// Win percentages as fractions such as 0.6
+    pub win_score_first: Option<f32>,
+    pub win_opp_score_first: Option<f32>,
+    pub win_lead_first_per: Option<f32>,
+    pub win_lead_second_per: Option<f32>,
+    pub win_outshoot_opp: Option<f32>,
+    pub win_outshot_by_opp: Option<f32>,
+    pub face_offs_taken: Option<f32>,
+    pub face_offs_won: Option<f32>,
+    pub face_offs_lost: Option<f32>,
+    pub shooting_pctg: Option<f32>,
+    pub save_pctg: Option<f32>,
+}
+
+/// League ranks parsed from ordinals such as "7th", where 1 is the best value
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamStatRankings {
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub wins: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub losses: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub ot: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub pts: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub pt_pctg: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub goals_per_game: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub goals_against_per_game: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    #[serde(rename = "evGGARatio")]
+    pub ev_gga_ratio: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub power_play_percentage: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub power_play_goals: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub power_play_goals_against: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub power_play_opportunities: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub penalty_kill_opportunities: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub penalty_kill_percentage: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub shots_per_game: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub shots_allowed: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub face_offs_won: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub face_offs_lost: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub face_off_win_percentage: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub save_pct_rank: Option<u16>,
+    #[serde(default, deserialize_with = "ordinal_as_option")]
+    pub shooting_pct_rank: Option<u16>,
+}
+
+impl<'de> Deserialize<'de> for TeamStats {
+    fn deserialize<D>(de: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        de.deserialize_map(StatsVisitor(PhantomData))
+    }
+}
+
+impl FromSplits for TeamStats {
+    const GROUPED: bool = false;
+
+    fn from_splits<'de, D>(
+        stats_type: &str,
+        _group: Option<&str>,
+        splits: D,
+    ) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let stats = match stats_type {
+            "statsSingleSeason" => TeamStats::Season(splits_or_empty(splits)?),
+            "regularSeasonStatRankings" => TeamStats::Rankings(splits_or_empty(splits)?),
+            _ => TeamStats::Other {
+                stats_type: stats_type.to_owned(),
+                splits: splits_or_empty(splits)?,
+            },
+        };
+
+        Ok(stats)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LeagueLeadersResponse {
+    #[serde(default)]
+    pub league_leaders: Vec<LeagueLeaders>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum LeaderCategory {
+    Goals,
+    Assists,
+    Points,
+    PlusMinus,
+    PenaltyMinutes,
+    PowerPlayGoals,
+    ShortHandedGoals,
+    GameWinningGoals,
+    TimeOnIcePerGame,
+    Wins,
+    Shutouts,
+    #[serde(rename = "gaa")]
+    GoalsAgainstAverage,
+    #[serde(rename = "savePct")]
+    SavePercentage,
+}
+
+impl From<&LeaderCategory> for String {
+    fn from(category: &LeaderCategory) -> String {
+        let s = match category {
+            LeaderCategory::Goals => "goals",
+            LeaderCategory::Assists => "assists",
+            LeaderCategory::Points => "points",
+            LeaderCategory::PlusMinus => "plusMinus",
+            LeaderCategory::PenaltyMinutes => "penaltyMinutes",
+            LeaderCategory::PowerPlayGoals => "powerPlayGoals",
+            LeaderCategory::ShortHandedGoals => "shortHandedGoals",
+            LeaderCategory::GameWinningGoals => "gameWinningGoals",
+            LeaderCategory::TimeOnIcePerGame => "timeOnIcePerGame",
+            LeaderCategory::Wins => "wins",
+            LeaderCategory::Shutouts => "shutouts",
+            LeaderCategory::GoalsAgainstAverage => "gaa",
+            LeaderCategory::SavePercentage => "savePct",
+        };
+        String::from(s)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LeagueLeaders {
+    #[serde(default, deserialize_with = "fail_as_none")]
+    pub leader_category: Option<LeaderCategory>,
+    pub season: Option<String>,
+    #[serde(default)]
+    pub leaders: Vec<Leader>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Leader {
+    pub rank: u16,
+    /// Formatted value such as "0.925"
+    #[serde(default)]
+    pub value: String,
+    pub person: PersonDetail,
+    pub team: Option<TeamDetail>,
+    pub season: Option<String>,
+}
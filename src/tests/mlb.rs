@@ -1,7 +1,16 @@
-use crate::*;
+use crate::{
+    model::mlb::{
+        AbstractGameState, DetailedState, GameEvent, GameFeedResponse, LeaderCategory, PersonStats,
+        RosterType, ScheduleHydration, ScheduleQuery, Season, StandingsType, StatsGroup,
+        StatsQuery, StatsType, TeamStats,
+    },
+    *,
+};
 use async_std::task;
 use chrono::NaiveDate;
+use futures::StreamExt;
 use mockito::{mock, Matcher};
+use std::time::Duration;
 
 #[test]
 fn test_teams() {
@@ -33,6 +42,314 @@ fn test_team() {
 
         let resp = client.get_team(team_id).await;
         assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let team = resp.unwrap();
+        assert_eq!(team.venue.unwrap().id, 10);
+        assert_eq!(team.division.unwrap().name, "American League West");
+        assert_eq!(team.sport.unwrap().id, 1);
+        assert_eq!(
+            team.spring_league.unwrap().abbreviation.as_deref(),
+            Some("CL")
+        );
+        assert!(team.parent_org_id.is_none());
+    });
+}
+
+#[test]
+fn test_affiliates() {
+    task::block_on(async {
+        let client = MlbClient::default();
+
+        let team_id = 146;
+        let _m = mock("GET", "/teams/affiliates?teamIds=146")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/mlb/team_affiliates.json")
+            .create();
+
+        let resp = client.get_affiliates(team_id).await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let affiliates = resp.unwrap();
+        assert_eq!(affiliates.len(), 10);
+        assert!(affiliates
+            .iter()
+            .all(|team| team.parent_org_id == Some(146)));
+
+        let top = affiliates[0].sport.as_ref().unwrap();
+        assert_eq!(MlbSport::from_id(top.id), Some(MlbSport::Aaa));
+
+        // Rookie Advanced has sport id 5442 but ranks above Rookie (16)
+        let sport_ids: Vec<_> = affiliates
+            .iter()
+            .map(|team| team.sport.as_ref().unwrap().id)
+            .collect();
+        assert_eq!(sport_ids, vec![11, 12, 13, 14, 14, 15, 5442, 16, 16, 21]);
+    });
+}
+
+#[test]
+fn test_leagues() {
+    task::block_on(async {
+        let client = MlbClient::default();
+
+        let _m = mock("GET", "/league?sportId=1")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/mlb/leagues.json")
+            .create();
+
+        let resp = client.get_leagues().await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let leagues = resp.unwrap();
+        assert_eq!(leagues.len(), 2);
+        assert_eq!(leagues[0].abbreviation.as_deref(), Some("AL"));
+    });
+}
+
+#[test]
+fn test_league() {
+    task::block_on(async {
+        let client = MlbClient::default();
+
+        let _m = mock("GET", "/league/103")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/mlb/league.json")
+            .create();
+
+        let resp = client.get_league(103).await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let league = resp.unwrap();
+        assert_eq!(league.name, "American League");
+        assert_eq!(league.num_games, Some(162));
+    });
+}
+
+#[test]
+fn test_league_empty() {
+    task::block_on(async {
+        let client = MlbClient::default();
+
+        let _m = mock("GET", "/league/999")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body(r#"{"leagues": []}"#)
+            .create();
+
+        match client.get_league(999).await {
+            Err(Error::NoLeagues) => {}
+            resp => panic!("Expected no leagues error, got {:?}", resp),
+        }
+    });
+}
+
+#[test]
+fn test_divisions() {
+    task::block_on(async {
+        let client = MlbClient::default();
+
+        let _m = mock("GET", "/divisions?sportId=1")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/mlb/divisions.json")
+            .create();
+
+        let resp = client.get_divisions(MlbSport::Mlb).await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let divisions = resp.unwrap();
+        assert_eq!(divisions.len(), 6);
+        assert_eq!(divisions[0].league.as_ref().unwrap().id, Some(103));
+    });
+}
+
+#[test]
+fn test_division() {
+    task::block_on(async {
+        let client = MlbClient::default();
+
+        let _m = mock("GET", "/divisions/200")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/mlb/division.json")
+            .create();
+
+        let resp = client.get_division(200).await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let division = resp.unwrap();
+        assert_eq!(division.name_short.as_deref(), Some("AL West"));
+    });
+}
+
+#[test]
+fn test_venues() {
+    task::block_on(async {
+        let client = MlbClient::default();
+
+        let _m = mock("GET", "/venues")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("sportIds".into(), "1".into()),
+                Matcher::UrlEncoded("hydrate".into(), "location,timezone,fieldInfo".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/mlb/venues.json")
+            .create();
+
+        let resp = client.get_venues().await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let venues = resp.unwrap();
+        assert_eq!(venues.len(), 3);
+        assert_eq!(
+            venues[2].field_info.as_ref().unwrap().roof_type.as_deref(),
+            Some("Retractable")
+        );
+    });
+}
+
+#[test]
+fn test_venue() {
+    task::block_on(async {
+        let client = MlbClient::default();
+
+        let _m = mock("GET", "/venues/10")
+            .match_query(Matcher::UrlEncoded(
+                "hydrate".into(),
+                "location,timezone,fieldInfo".into(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/mlb/venue.json")
+            .create();
+
+        let resp = client.get_venue(10).await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let venue = resp.unwrap();
+        let location = venue.location.unwrap();
+        assert_eq!(location.state_abbrev.as_deref(), Some("CA"));
+        assert_eq!(location.default_coordinates.unwrap().latitude, 37.751595);
+        assert_eq!(venue.time_zone.unwrap().id, "America/Los_Angeles");
+
+        let field_info = venue.field_info.unwrap();
+        assert_eq!(field_info.capacity, Some(46847));
+        assert_eq!(field_info.center, Some(400));
+    });
+}
+
+#[test]
+fn test_current_season() {
+    task::block_on(async {
+        let client = MlbClient::default();
+
+        let _m = mock("GET", "/seasons?sportId=1")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/mlb/season_current.json")
+            .create();
+
+        let resp = client.get_current_season().await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let season = resp.unwrap();
+        assert_eq!(season.season_id, "2019");
+        assert_eq!(season.all_star_date, NaiveDate::from_ymd_opt(2019, 7, 9));
+        assert!(season.is_postseason(NaiveDate::from_ymd_opt(2019, 10, 30).unwrap()));
+    });
+}
+
+#[test]
+fn test_season_for_date() {
+    task::block_on(async {
+        let client = MlbClient::default();
+
+        let _m = mock("GET", "/seasons/all?sportId=1")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/mlb/seasons.json")
+            .expect(3)
+            .create();
+
+        let date = NaiveDate::from_ymd_opt(2019, 6, 12).unwrap();
+        let season = client.season_for_date(date).await.unwrap().unwrap();
+        assert_eq!(season.season_id, "2019");
+        assert!(season.contains(date));
+
+        // Spring training belongs to the upcoming season
+        let date = NaiveDate::from_ymd_opt(2019, 3, 1).unwrap();
+        let season = client.season_for_date(date).await.unwrap().unwrap();
+        assert_eq!(season.season_id, "2019");
+        assert!(season.contains(date));
+
+        let date = NaiveDate::from_ymd_opt(2017, 6, 1).unwrap();
+        assert!(client.season_for_date(date).await.unwrap().is_none());
+
+        _m.assert();
+    });
+}
+
+#[test]
+fn test_season_for_date_offseason() {
+    task::block_on(async {
+        let client = MlbClient::default();
+
+        let _m = mock("GET", "/seasons/all?sportId=1")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/mlb/seasons.json")
+            .create();
+
+        let seasons = client.get_seasons().await.unwrap();
+
+        // Offseason belongs to the season that just ended
+        let date = NaiveDate::from_ymd_opt(2018, 12, 15).unwrap();
+        let season = Season::for_date(&seasons, date).unwrap();
+        assert_eq!(season.season_id, "2018");
+        assert!(!season.contains(date));
+    });
+}
+
+#[test]
+fn test_schedule_game_season() {
+    task::block_on(async {
+        let client = MlbClient::default();
+        let start = NaiveDate::from_ymd_opt(2019, 6, 11).unwrap();
+        let end = NaiveDate::from_ymd_opt(2019, 6, 13).unwrap();
+
+        let _m = mock("GET", "/seasons/all?sportId=1")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/mlb/seasons.json")
+            .create();
+
+        let _m2 = mock("GET", "/schedule")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("sportId".into(), "1".into()),
+                Matcher::UrlEncoded("startDate".into(), "2019-06-11".into()),
+                Matcher::UrlEncoded("endDate".into(), "2019-06-13".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/mlb/schedule_range.json")
+            .create();
+
+        let seasons = client.get_seasons().await.unwrap();
+        let query = ScheduleQuery::new().date_range(start, end);
+        let dates = client.get_schedule(query).await.unwrap();
+
+        for schedule in &dates {
+            for game in &schedule.games {
+                let season = game.season_of(&seasons).unwrap();
+                assert_eq!(season.season_id, "2019");
+                assert!(season.contains(schedule.date));
+            }
+        }
+        assert!(dates[0].games[0].season_of(&seasons[..1]).is_none());
     });
 }
 
@@ -54,6 +371,16 @@ fn test_schedule() {
 
         let resp = client.get_schedule_for(date).await;
         assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let game = &resp.unwrap().games[0];
+        let away = &game.teams.away;
+        assert_eq!(away.is_winner, Some(true));
+        assert_eq!(
+            away.league_record.as_ref().unwrap().pct.as_deref(),
+            Some(".493")
+        );
+        assert_eq!(game.teams.home.league_record.as_ref().unwrap().losses, 43);
+        assert_eq!(game.venue.as_ref().unwrap().name, "Marlins Park");
     });
 }
 
@@ -78,6 +405,153 @@ fn test_schedule_empty() {
     });
 }
 
+#[test]
+fn test_schedule_status() {
+    task::block_on(async {
+        let client = MlbClient::default();
+        let date = NaiveDate::from_ymd_opt(2018, 6, 14).unwrap();
+
+        let _m = mock("GET", "/schedule")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("date".into(), "2018-06-14".into()),
+                Matcher::UrlEncoded("sportId".into(), "1".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/mlb/schedule_status.json")
+            .create();
+
+        let resp = client.get_schedule_for(date).await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let games = resp.unwrap().games;
+
+        let delayed = &games[0].status;
+        assert!(delayed.is_live());
+        assert_eq!(delayed.detailed_state, DetailedState::Delayed);
+        assert_eq!(delayed.reason.as_deref(), Some("Rain"));
+
+        let postponed = &games[1].status;
+        assert!(postponed.is_final());
+        assert!(postponed.is_postponed());
+        assert!(games[1].teams.home.score.is_none());
+
+        let preview = &games[2].status;
+        assert!(preview.is_preview());
+        assert!(preview.start_time_tbd);
+        assert_eq!(preview.detailed_state, DetailedState::Scheduled);
+    });
+}
+
+#[test]
+fn test_detailed_state_cause() {
+    let states = [
+        ("Postponed: Rain", DetailedState::Postponed),
+        ("Cancelled: Inclement Weather", DetailedState::Cancelled),
+        ("Completed Early: Rain", DetailedState::Final),
+        ("Final: Tied", DetailedState::Final),
+        ("Game Over: Tied", DetailedState::GameOver),
+        ("Delayed Start: Rain", DetailedState::Delayed),
+        (
+            "Manager challenge",
+            DetailedState::Other("Manager challenge".into()),
+        ),
+    ];
+
+    for (s, state) in states.iter() {
+        let parsed = serde_json::from_value::<DetailedState>(serde_json::json!(s)).unwrap();
+        assert_eq!(&parsed, state, "{}", s);
+    }
+}
+
+#[test]
+fn test_schedule_range() {
+    task::block_on(async {
+        let client = MlbClient::default();
+        let start = NaiveDate::from_ymd_opt(2019, 6, 11).unwrap();
+        let end = NaiveDate::from_ymd_opt(2019, 6, 13).unwrap();
+
+        let _m = mock("GET", "/schedule")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("sportId".into(), "1".into()),
+                Matcher::UrlEncoded("startDate".into(), "2019-06-11".into()),
+                Matcher::UrlEncoded("endDate".into(), "2019-06-13".into()),
+                Matcher::UrlEncoded("teamId".into(), "143".into()),
+                Matcher::UrlEncoded("opponentId".into(), "115".into()),
+                Matcher::UrlEncoded("gameType".into(), "R".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/mlb/schedule_range.json")
+            .create();
+
+        let query = ScheduleQuery::new()
+            .date_range(start, end)
+            .team(143)
+            .opponent(115)
+            .game_type("R");
+        let resp = client.get_schedule(query).await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let dates = resp.unwrap();
+        assert_eq!(dates.len(), 3);
+        assert_eq!(dates[1].date, NaiveDate::from_ymd_opt(2019, 6, 12).unwrap());
+        assert!(dates
+            .iter()
+            .all(|d| d.games.iter().all(|g| g.teams.home.detail.id == 143)));
+    });
+}
+
+#[test]
+fn test_schedule_hydrated() {
+    task::block_on(async {
+        let client = MlbClient::default();
+        let date = NaiveDate::from_ymd_opt(2019, 6, 12).unwrap();
+
+        let _m = mock("GET", "/schedule")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("sportId".into(), "1".into()),
+                Matcher::UrlEncoded("startDate".into(), "2019-06-12".into()),
+                Matcher::UrlEncoded("endDate".into(), "2019-06-12".into()),
+                Matcher::UrlEncoded(
+                    "hydrate".into(),
+                    "linescore,broadcasts(all),probablePitcher,venue(location,timezone,fieldInfo),weather,tickets".into(),
+                ),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/mlb/schedule_hydrated.json")
+            .create();
+
+        let query = ScheduleQuery::new()
+            .date(date)
+            .hydrate(ScheduleHydration::Linescore)
+            .hydrate(ScheduleHydration::Broadcasts)
+            .hydrate(ScheduleHydration::ProbablePitchers)
+            .hydrate(ScheduleHydration::Venue)
+            .hydrate(ScheduleHydration::Weather)
+            .hydrate(ScheduleHydration::Tickets)
+            .hydrate(ScheduleHydration::Linescore);
+        let resp = client.get_schedule(query).await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let game = &resp.unwrap()[0].games[0];
+        assert_eq!(game.linescore.as_ref().unwrap().current_inning, Some(9));
+        assert_eq!(game.broadcasts.len(), 3);
+        assert_eq!(game.broadcasts[2].r#type, "FM");
+        assert_eq!(
+            game.teams.home.probable_pitcher.as_ref().unwrap().full_name,
+            "Aaron Nola"
+        );
+        assert_eq!(game.weather.as_ref().unwrap().temp, "72");
+        assert!(game.tickets[0].ticket_links.contains_key("desktop"));
+
+        let venue = game.venue.as_ref().unwrap();
+        assert_eq!(venue.name, "Citizens Bank Park");
+        assert_eq!(venue.time_zone.as_ref().unwrap().tz, "EDT");
+    });
+}
+
 #[test]
 fn test_game_content_pre() {
     task::block_on(async {
@@ -143,5 +617,677 @@ fn test_game_linescore_post() {
 
         let resp = client.get_game_linescore(game_pk).await;
         assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let linescore = resp.unwrap();
+        assert_eq!(linescore.current_inning, Some(9));
+        assert_eq!(linescore.innings.len(), 9);
+        assert_eq!(linescore.innings[8].home.runs, None);
+        assert_eq!(linescore.teams.home.runs, 9);
+        assert_eq!(linescore.teams.away.runs, 3);
+        assert_eq!(linescore.outs, Some(3));
+        assert_eq!(linescore.offense.batter.unwrap().id, 596_115);
+        assert_eq!(linescore.defense.team.unwrap().id, 143);
+    });
+}
+
+#[test]
+fn test_client_builder() {
+    task::block_on(async {
+        let client = MlbClientBuilder::new()
+            .base_url(format!("{}/", mockito::server_url()))
+            .user_agent("stats-api-test")
+            .timeout(Duration::from_secs(5))
+            .connect_timeout(Duration::from_secs(1))
+            .max_connections(4)
+            .max_connections_per_host(2)
+            .build()
+            .unwrap();
+
+        let team_id = 133;
+        let _m = mock("GET", "/teams/133")
+            .match_header("user-agent", "stats-api-test")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/mlb/team.json")
+            .create();
+
+        let resp = client.get_team(team_id).await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+    });
+}
+
+#[test]
+fn test_client_builder_sport() {
+    task::block_on(async {
+        let client = MlbClientBuilder::new()
+            .sport(MlbSport::Aaa)
+            .build()
+            .unwrap();
+
+        let _m = mock("GET", "/teams?sportId=11")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/mlb/teams.json")
+            .create();
+
+        let resp = client.get_teams().await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+        _m.assert();
+    });
+}
+
+#[test]
+fn test_client_builder_invalid_url() {
+    for base in &["statsapi.mlb.com/api/v1", "not a url"] {
+        match MlbClientBuilder::new().base_url(*base).build() {
+            Err(Error::InvalidUrl(url)) => assert_eq!(url, *base),
+            resp => panic!("Expected invalid url error, got {:?}", resp.map(|_| ())),
+        }
+    }
+}
+
+#[test]
+fn test_game_linescore_server_error() {
+    task::block_on(async {
+        let client = MlbClient::default();
+
+        let game_pk = 530_430;
+        let _m = mock("GET", "/game/530430/linescore")
+            .with_status(503)
+            .with_header("content-type", "text/html")
+            .with_body("<html>Service Unavailable</html>")
+            .create();
+
+        match client.get_game_linescore(game_pk).await {
+            Err(Error::Server(e)) => {
+                assert_eq!(e.code, 503);
+                assert_eq!(e.message, None);
+                assert_eq!(e.body, "<html>Service Unavailable</html>");
+            }
+            resp => panic!("Expected server error, got {:?}", resp),
+        }
+    });
+}
+
+#[test]
+fn test_game_feed() {
+    task::block_on(async {
+        let client = MlbClient::default();
+
+        let game_pk = 530_429;
+        let _m = mock("GET", "/game/530429/feed/live")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/mlb/game_feed.json")
+            .create();
+
+        let resp = client.get_game_feed(game_pk).await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let feed = resp.unwrap();
+        assert_eq!(feed.game_data.teams.away.id, 115);
+
+        let home_run = feed.live_data.plays.scoring().next().unwrap();
+        assert!(home_run.about.is_scoring_play);
+        assert_eq!(home_run.matchup.batter.id, 656_555);
+        assert_eq!(home_run.runners[0].movement.end.as_deref(), Some("score"));
+
+        let pitch = home_run.play_events.last().unwrap();
+        let pitch_type = pitch.details.r#type.as_ref().unwrap();
+        assert_eq!(pitch_type.code, "FF");
+        assert_eq!(pitch.pitch_data.as_ref().unwrap().zone, Some(5));
+
+        let hit = pitch.hit_data.as_ref().unwrap();
+        assert_eq!(hit.launch_speed, Some(108.3));
+        assert_eq!(hit.total_distance, Some(421.0));
+    });
+}
+
+#[test]
+fn test_game_feed_url() {
+    task::block_on(async {
+        let client = MlbClientBuilder::new()
+            .base_url(format!("{}/api/v1", mockito::server_url()))
+            .build()
+            .unwrap();
+
+        let game_pk = 530_429;
+        let _m = mock("GET", "/api/v1.1/game/530429/feed/live")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/mlb/game_feed.json")
+            .create();
+
+        let resp = client.get_game_feed(game_pk).await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+    });
+}
+
+#[test]
+fn test_game_feed_events() {
+    let body = std::fs::read_to_string("./src/tests/responses/mlb/game_feed.json").unwrap();
+    let feed = serde_json::from_str::<GameFeedResponse>(&body).unwrap();
+
+    let mut previous = feed.clone();
+    previous.live_data.plays.all_plays.truncate(2);
+    previous.live_data.linescore.teams.home.runs = 8;
+    previous.live_data.linescore.current_inning = Some(8);
+    previous.live_data.linescore.is_top_inning = false;
+    previous.game_data.status.abstract_game_state = AbstractGameState::Live;
+    previous.game_data.status.detailed_state = DetailedState::InProgress;
+
+    let events = feed.events_since(&previous);
+    assert_eq!(events.len(), 4);
+    match &events[0] {
+        GameEvent::Play(play) => assert_eq!(play.result.event.as_deref(), Some("Strikeout")),
+        other => panic!("expected play, got {:?}", other),
+    }
+    match &events[1] {
+        GameEvent::Score { away, home } => assert_eq!((*away, *home), (3, 9)),
+        other => panic!("expected score, got {:?}", other),
+    }
+    match &events[2] {
+        GameEvent::Inning {
+            inning,
+            is_top_inning,
+        } => assert_eq!((*inning, *is_top_inning), (9, true)),
+        other => panic!("expected inning, got {:?}", other),
+    }
+    match &events[3] {
+        GameEvent::Status(status) => assert!(status.is_final()),
+        other => panic!("expected status, got {:?}", other),
+    }
+
+    assert!(feed.events_since(&feed).is_empty());
+}
+
+#[test]
+fn test_watch_game() {
+    task::block_on(async {
+        let client = MlbClient::default();
+
+        let _m = mock("GET", "/game/530429/feed/live")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/mlb/game_feed.json")
+            .create();
+
+        let events = client
+            .watch_game(530_429, Duration::from_secs(5))
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(events.len(), 1);
+        match &events[0] {
+            Ok(GameEvent::Status(status)) => assert!(status.is_final()),
+            other => panic!("expected final status, got {:?}", other),
+        }
+    });
+}
+
+#[test]
+fn test_watch_game_postponed() {
+    task::block_on(async {
+        let client = MlbClient::default();
+
+        let _m = mock("GET", "/game/530429/feed/live")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/mlb/game_feed_postponed.json")
+            .create();
+
+        let events = client
+            .watch_game(530_429, Duration::from_secs(5))
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(events.len(), 1);
+        match &events[0] {
+            Ok(GameEvent::Status(status)) => {
+                assert!(status.is_postponed());
+                assert!(status.is_over());
+            }
+            other => panic!("expected postponed status, got {:?}", other),
+        }
+    });
+}
+
+#[test]
+fn test_update_game_feed() {
+    task::block_on(async {
+        let client = MlbClient::default();
+
+        let _m = mock("GET", "/game/530429/feed/live")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/mlb/game_feed.json")
+            .create();
+        let _diff = mock("GET", "/game/530429/feed/live/diffPatch")
+            .match_query(Matcher::UrlEncoded(
+                "startTimecode".into(),
+                "20190614_043502".into(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/mlb/game_feed_diff.json")
+            .create();
+
+        let resp = client.get_live_game_feed(530_429).await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let mut live_feed = resp.unwrap();
+        let resp = client.update_game_feed(&mut live_feed).await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        assert_eq!(live_feed.time_stamp(), Some("20190614_043522"));
+        let feed = live_feed.feed();
+        assert_eq!(feed.live_data.linescore.teams.home.hits, 14);
+        assert_eq!(feed.meta_data.as_ref().unwrap().logical_events.len(), 3);
+    });
+}
+
+#[test]
+fn test_update_game_feed_fallback() {
+    task::block_on(async {
+        let client = MlbClient::default();
+
+        let _m = mock("GET", "/game/530429/feed/live")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/mlb/game_feed.json")
+            .expect(2)
+            .create();
+        let _diff = mock("GET", "/game/530429/feed/live/diffPatch")
+            .match_query(Matcher::Any)
+            .with_status(500)
+            .create();
+
+        let mut live_feed = client.get_live_game_feed(530_429).await.unwrap();
+        let resp = client.update_game_feed(&mut live_feed).await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        _m.assert();
+        assert_eq!(live_feed.time_stamp(), Some("20190614_043502"));
+    });
+}
+
+#[test]
+fn test_game_boxscore() {
+    task::block_on(async {
+        let client = MlbClient::default();
+
+        let game_pk = 530_429;
+        let _m = mock("GET", "/game/530429/boxscore")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/mlb/game_boxscore.json")
+            .create();
+
+        let resp = client.get_game_boxscore(game_pk).await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let boxscore = resp.unwrap();
+        let home = &boxscore.teams.home;
+        assert_eq!(home.team_stats.batting.as_ref().unwrap().runs, 9);
+
+        let hoskins = &home.players["ID656555"].stats;
+        assert_eq!(hoskins.batting.as_ref().unwrap().home_runs, 1);
+        assert!(hoskins.pitching.is_none());
+
+        let morgan = &home.players["ID605388"].stats;
+        let pitching = morgan.pitching.as_ref().unwrap();
+        assert_eq!(pitching.note.as_deref(), Some("(W, 3-1)"));
+        assert!(morgan.batting.is_none());
+
+        assert_eq!(boxscore.officials[0].official_type, "Home Plate");
+    });
+}
+
+#[test]
+fn test_standings() {
+    task::block_on(async {
+        let client = MlbClient::default();
+
+        let _m = mock("GET", "/standings")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("leagueId".into(), "103,104".into()),
+                Matcher::UrlEncoded("standingsTypes".into(), "regularSeason".into()),
+                Matcher::UrlEncoded("season".into(), "2019".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/mlb/standings.json")
+            .create();
+
+        let resp = client
+            .get_standings(Some("2019"), StandingsType::RegularSeason)
+            .await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let records = resp.unwrap();
+        assert_eq!(
+            records[0].standings_type,
+            Some(StandingsType::RegularSeason)
+        );
+
+        let dodgers = &records[0].team_records[0];
+        assert_eq!(dodgers.games_back, None);
+        assert_eq!(dodgers.wild_card_rank, None);
+        assert_eq!(dodgers.clinch_indicator.as_deref(), Some("z"));
+
+        let rockies = &records[0].team_records[2];
+        assert_eq!(rockies.team.id, 115);
+        assert_eq!(rockies.games_back, Some(35.0));
+        assert_eq!(rockies.wild_card_rank, Some(7));
+        assert_eq!(rockies.streak.as_ref().unwrap().streak_code, "L2");
+    });
+}
+
+#[test]
+fn test_team_roster() {
+    task::block_on(async {
+        let client = MlbClient::default();
+
+        let team_id = 133;
+        let _m = mock("GET", "/teams/133/roster")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("rosterType".into(), "40Man".into()),
+                Matcher::UrlEncoded("season".into(), "2019".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/mlb/team_roster.json")
+            .create();
+
+        let resp = client
+            .get_team_roster(team_id, RosterType::FortyMan, Some("2019"))
+            .await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let roster = resp.unwrap();
+        let puk = roster.iter().find(|e| e.person.id == 621_345).unwrap();
+        assert_eq!(puk.jersey_number.as_deref(), Some("33"));
+        assert_eq!(puk.position.abbreviation, "P");
+        assert_eq!(puk.status.as_ref().unwrap().code, "D10");
+    });
+}
+
+#[test]
+fn test_person() {
+    task::block_on(async {
+        let client = MlbClient::default();
+
+        let person_id = 596_115;
+        let _m = mock("GET", "/people/596115?hydrate=currentTeam")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/mlb/person.json")
+            .create();
+
+        let resp = client.get_person(person_id).await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let person = resp.unwrap();
+        assert_eq!(person.birth_date, NaiveDate::from_ymd_opt(1992, 11, 15));
+        assert_eq!(person.bat_side.as_ref().unwrap().code, "R");
+        assert_eq!(person.current_team.as_ref().unwrap().id, 115);
+        assert!(person
+            .headshot_url(120)
+            .contains("/people/596115/headshot/"));
+    });
+}
+
+#[test]
+fn test_people() {
+    task::block_on(async {
+        let client = MlbClient::default();
+
+        let _m = mock("GET", "/people")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("personIds".into(), "596115,656555".into()),
+                Matcher::UrlEncoded("hydrate".into(), "currentTeam".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/mlb/people.json")
+            .create();
+
+        let resp = client.get_people(&[596_115, 656_555]).await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let people = resp.unwrap();
+        assert_eq!(people.len(), 2);
+        assert_eq!(people[1].full_name, "Rhys Hoskins");
+    });
+}
+
+#[test]
+fn test_person_stats_hitting() {
+    task::block_on(async {
+        let client = MlbClient::default();
+
+        let _m = mock("GET", "/people/596115/stats")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("stats".into(), "yearByYear".into()),
+                Matcher::UrlEncoded("group".into(), "hitting".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/mlb/person_stats_hitting.json")
+            .create();
+
+        let query = StatsQuery::new(StatsType::YearByYear, StatsGroup::Hitting);
+        let resp = client.get_person_stats(596_115, query).await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let stats = resp.unwrap();
+        match &stats[0] {
+            PersonStats::Hitting(hitting) => {
+                assert_eq!(hitting.stats_type, Some(StatsType::YearByYear));
+                assert_eq!(hitting.splits.len(), 4);
+
+                let split = &hitting.splits[2];
+                assert_eq!(split.season.as_deref(), Some("2018"));
+                assert_eq!(split.stat.home_runs, 37);
+                assert_eq!(split.stat.avg.as_deref(), Some(".291"));
+            }
+            other => panic!("expected hitting stats, got {:?}", other.group()),
+        }
+    });
+}
+
+#[test]
+fn test_person_stats_error_path() {
+    task::block_on(async {
+        let client = MlbClient::default();
+
+        let _m = mock("GET", "/people/596115/stats")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body(
+                r#"{"stats": [{
+                    "type": {"displayName": "season"},
+                    "group": {"displayName": "hitting"},
+                    "splits": [{"season": "2019", "stat": {"homeRuns": "x"}}]
+                }]}"#,
+            )
+            .create();
+
+        let query = StatsQuery::new(StatsType::Season, StatsGroup::Hitting);
+        match client.get_person_stats(596_115, query).await {
+            Err(Error::Json { path, .. }) => assert_eq!(path, "stats[0].splits[0].stat.homeRuns"),
+            resp => panic!("Expected json error, got {:?}", resp),
+        }
+    });
+}
+
+#[test]
+fn test_person_stats_pitching() {
+    task::block_on(async {
+        let client = MlbClient::default();
+
+        let _m = mock("GET", "/people/605388/stats")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("stats".into(), "gameLog".into()),
+                Matcher::UrlEncoded("group".into(), "pitching".into()),
+                Matcher::UrlEncoded("season".into(), "2019".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/mlb/person_stats_pitching.json")
+            .create();
+
+        let query = StatsQuery::new(StatsType::GameLog, StatsGroup::Pitching).season("2019");
+        let resp = client.get_person_stats(605_388, query).await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let stats = resp.unwrap();
+        match &stats[0] {
+            PersonStats::Pitching(pitching) => {
+                let split = &pitching.splits[1];
+                assert_eq!(split.date, NaiveDate::from_ymd_opt(2019, 6, 13));
+                assert_eq!(split.game.as_ref().unwrap().game_pk, 530_429);
+                assert_eq!(split.is_home, Some(true));
+                assert_eq!(split.stat.wins, 1);
+                assert_eq!(split.stat.note.as_deref(), Some("(W, 3-1)"));
+            }
+            other => panic!("expected pitching stats, got {:?}", other.group()),
+        }
+    });
+}
+
+#[test]
+fn test_team_stats() {
+    task::block_on(async {
+        let client = MlbClient::default();
+
+        let _m = mock("GET", "/teams/143/stats")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded(
+                    "stats".into(),
+                    "statsSingleSeason,regularSeasonStatRankings".into(),
+                ),
+                Matcher::UrlEncoded("group".into(), "hitting,pitching".into()),
+                Matcher::UrlEncoded("season".into(), "2019".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/mlb/team_stats.json")
+            .create();
+
+        let resp = client.get_team_stats(143, Some("2019")).await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let stats = resp.unwrap();
+        assert_eq!(stats.len(), 4);
+        for group in stats {
+            match group {
+                TeamStats::Hitting(splits) => assert_eq!(splits[0].stat.home_runs, 215),
+                TeamStats::Pitching(splits) => {
+                    assert_eq!(splits[0].stat.era.as_deref(), Some("4.53"))
+                }
+                TeamStats::HittingRankings(splits) => {
+                    assert_eq!(splits[0].stat.avg, Some(22))
+                }
+                TeamStats::PitchingRankings(splits) => {
+                    assert_eq!(splits[0].stat.saves, Some(9))
+                }
+                other => panic!("expected hitting or pitching stats, got {:?}", other),
+            }
+        }
+    });
+}
+
+#[test]
+fn test_team_stats_other_group() {
+    task::block_on(async {
+        let client = MlbClient::default();
+
+        let _m = mock("GET", "/teams/143/stats")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body(
+                r#"{"stats": [{
+                    "type": {"displayName": "regularSeasonStatRankings"},
+                    "group": {"displayName": "fielding"},
+                    "splits": [{"stat": {"fielding": "3rd"}}]
+                }]}"#,
+            )
+            .create();
+
+        let resp = client.get_team_stats(143, None).await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        match &resp.unwrap()[0] {
+            TeamStats::Other {
+                stats_type,
+                group,
+                splits,
+            } => {
+                assert_eq!(stats_type, "regularSeasonStatRankings");
+                assert_eq!(group, "fielding");
+                assert_eq!(splits[0]["stat"]["fielding"], "3rd");
+            }
+            other => panic!("expected other stats, got {:?}", other),
+        }
+    });
+}
+
+#[test]
+fn test_team_stats_error_path() {
+    task::block_on(async {
+        let client = MlbClient::default();
+
+        let _m = mock("GET", "/teams/143/stats")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body(
+                r#"{"stats": [{
+                    "type": {"displayName": "statsSingleSeason"},
+                    "group": {"displayName": "pitching"},
+                    "splits": [{"stat": {"wins": "x"}}]
+                }]}"#,
+            )
+            .create();
+
+        match client.get_team_stats(143, None).await {
+            Err(Error::Json { path, .. }) => assert_eq!(path, "stats[0].splits[0].stat.wins"),
+            resp => panic!("Expected json error, got {:?}", resp),
+        }
+    });
+}
+
+#[test]
+fn test_leaders() {
+    task::block_on(async {
+        let client = MlbClient::default();
+
+        let _m = mock("GET", "/stats/leaders")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("leaderCategories".into(), "homeRuns".into()),
+                Matcher::UrlEncoded("statGroup".into(), "hitting".into()),
+                Matcher::UrlEncoded("sportId".into(), "1".into()),
+                Matcher::UrlEncoded("limit".into(), "3".into()),
+                Matcher::UrlEncoded("season".into(), "2019".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/mlb/leaders.json")
+            .create();
+
+        let resp = client
+            .get_leaders(LeaderCategory::HomeRuns, Some("2019"), 3)
+            .await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let leaders = resp.unwrap();
+        assert_eq!(leaders.len(), 3);
+        assert_eq!(leaders[0].rank, 1);
+        assert_eq!(leaders[0].value, "53");
+        assert_eq!(leaders[0].person.full_name, "Pete Alonso");
+        assert_eq!(leaders[1].team.as_ref().unwrap().id, 113);
     });
 }
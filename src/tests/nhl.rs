@@ -1,7 +1,16 @@
-use crate::*;
+use crate::{
+    model::nhl::{
+        AbstractGameState, DetailedState, EventType, GameEvent, GameFeedResponse, LeaderCategory,
+        PlayerStats, ScheduleExpand, ScheduleQuery, Season, StandingsType, StatsQuery, StatsType,
+        TeamStats,
+    },
+    *,
+};
 use async_std::task;
 use chrono::NaiveDate;
-use mockito::mock;
+use futures::StreamExt;
+use mockito::{mock, Matcher};
+use std::time::Duration;
 
 #[test]
 fn test_teams() {
@@ -33,6 +42,247 @@ fn test_team() {
 
         let resp = client.get_team(team_id).await;
         assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let team = resp.unwrap();
+        let venue = team.venue.unwrap();
+        assert_eq!(venue.city.as_deref(), Some("Newark"));
+        assert_eq!(venue.time_zone.unwrap().tz, "EST");
+        assert_eq!(team.division.unwrap().id, Some(18));
+        assert_eq!(team.franchise.unwrap().franchise_id, 23);
+    });
+}
+
+#[test]
+fn test_teams_by_division() {
+    task::block_on(async {
+        let client = NhlClient::default();
+
+        let _m = mock("GET", "/teams")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/nhl/teams.json")
+            .create();
+
+        let resp = client.teams_by_division().await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let divisions = resp.unwrap();
+        let ids: Vec<_> = divisions.iter().map(|(d, _)| d.id).collect();
+        assert_eq!(ids, vec![Some(25), Some(26), Some(27), Some(28)]);
+        assert_eq!(divisions.iter().map(|(_, t)| t.len()).sum::<usize>(), 31);
+    });
+}
+
+#[test]
+fn test_divisions() {
+    task::block_on(async {
+        let client = NhlClient::default();
+
+        let _m = mock("GET", "/divisions")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/nhl/divisions.json")
+            .create();
+
+        let resp = client.get_divisions().await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let divisions = resp.unwrap();
+        assert_eq!(divisions.len(), 4);
+        assert_eq!(divisions[0].conference.as_ref().unwrap().id, Some(6));
+    });
+}
+
+#[test]
+fn test_division() {
+    task::block_on(async {
+        let client = NhlClient::default();
+
+        let _m = mock("GET", "/divisions/18")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/nhl/division.json")
+            .create();
+
+        let resp = client.get_division(18).await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let division = resp.unwrap();
+        assert_eq!(division.name, "Metropolitan");
+        assert_eq!(division.abbreviation.as_deref(), Some("M"));
+    });
+}
+
+#[test]
+fn test_conferences() {
+    task::block_on(async {
+        let client = NhlClient::default();
+
+        let _m = mock("GET", "/conferences")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/nhl/conferences.json")
+            .create();
+
+        let resp = client.get_conferences().await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let conferences = resp.unwrap();
+        assert_eq!(conferences[0].short_name.as_deref(), Some("East"));
+    });
+}
+
+#[test]
+fn test_venues() {
+    task::block_on(async {
+        let client = NhlClient::default();
+
+        let _m = mock("GET", "/venues")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/nhl/venues.json")
+            .create();
+
+        let resp = client.get_venues().await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        assert_eq!(resp.unwrap().len(), 3);
+    });
+}
+
+#[test]
+fn test_venue() {
+    task::block_on(async {
+        let client = NhlClient::default();
+
+        let _m = mock("GET", "/venues/5034")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/nhl/venue.json")
+            .create();
+
+        let resp = client.get_venue(5034).await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        assert_eq!(resp.unwrap().name, "PPG Paints Arena");
+    });
+}
+
+#[test]
+fn test_current_season() {
+    task::block_on(async {
+        let client = NhlClient::default();
+
+        let _m = mock("GET", "/seasons/current")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/nhl/season_current.json")
+            .create();
+
+        let resp = client.get_current_season().await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let season = resp.unwrap();
+        assert_eq!(season.season_id, "20192020");
+        assert_eq!(season.number_of_games, 82);
+        assert!(season.is_postseason(NaiveDate::from_ymd_opt(2020, 8, 15).unwrap()));
+    });
+}
+
+#[test]
+fn test_season_for_date() {
+    task::block_on(async {
+        let client = NhlClient::default();
+
+        let _m = mock("GET", "/seasons")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/nhl/seasons.json")
+            .expect(4)
+            .create();
+
+        let date = NaiveDate::from_ymd_opt(2019, 12, 10).unwrap();
+        let season = client.season_for_date(date).await.unwrap().unwrap();
+        assert_eq!(season.season_id, "20192020");
+
+        // Offseason and preseason belong to the upcoming season
+        let date = NaiveDate::from_ymd_opt(2019, 8, 1).unwrap();
+        let season = client.season_for_date(date).await.unwrap().unwrap();
+        assert_eq!(season.season_id, "20192020");
+        assert!(!season.contains(date));
+
+        let date = NaiveDate::from_ymd_opt(2019, 9, 20).unwrap();
+        let season = client.season_for_date(date).await.unwrap().unwrap();
+        assert_eq!(season.season_id, "20192020");
+
+        // Past the last listed season
+        let date = NaiveDate::from_ymd_opt(2020, 10, 15).unwrap();
+        let season = client.season_for_date(date).await.unwrap().unwrap();
+        assert_eq!(season.season_id, "20192020");
+
+        _m.assert();
+    });
+}
+
+#[test]
+fn test_season_for_date_before_seasons() {
+    task::block_on(async {
+        let client = NhlClient::default();
+
+        let _m = mock("GET", "/seasons")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/nhl/seasons.json")
+            .create();
+
+        let date = NaiveDate::from_ymd_opt(2017, 12, 1).unwrap();
+        assert!(client.season_for_date(date).await.unwrap().is_none());
+
+        let date = NaiveDate::from_ymd_opt(2018, 10, 3).unwrap();
+        let season = client.season_for_date(date).await.unwrap().unwrap();
+        assert_eq!(season.season_id, "20182019");
+    });
+}
+
+#[test]
+fn test_schedule_game_season() {
+    task::block_on(async {
+        let client = NhlClient::default();
+        let start = NaiveDate::from_ymd_opt(2019, 11, 27).unwrap();
+        let end = NaiveDate::from_ymd_opt(2019, 12, 1).unwrap();
+
+        let _m = mock("GET", "/seasons")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/nhl/seasons.json")
+            .create();
+
+        let _m2 = mock("GET", "/schedule")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("startDate".into(), "2019-11-27".into()),
+                Matcher::UrlEncoded("endDate".into(), "2019-12-01".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/nhl/schedule_range.json")
+            .create();
+
+        let seasons = client.get_seasons().await.unwrap();
+        let query = ScheduleQuery::new().date_range(start, end);
+        let dates = client.get_schedule(query).await.unwrap();
+
+        for schedule in &dates {
+            for game in &schedule.games {
+                let season = game.season_of(&seasons).unwrap();
+                assert_eq!(season.season_id, "20192020");
+                assert!(season.contains(schedule.date));
+                assert_eq!(
+                    Season::for_date(&seasons, schedule.date).unwrap().season_id,
+                    season.season_id
+                );
+            }
+        }
+        assert!(dates[0].games[0].season_of(&seasons[..1]).is_none());
     });
 }
 
@@ -50,6 +300,16 @@ fn test_schedule() {
 
         let resp = client.get_schedule_for(date).await;
         assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let game = &resp.unwrap().games[0];
+        assert!(game.status.is_final());
+        assert!(!game.status.is_live());
+        assert_eq!(game.status.detailed_state, DetailedState::Final);
+
+        let away_record = game.teams.away.league_record.as_ref().unwrap();
+        assert_eq!(away_record.wins, 15);
+        assert_eq!(away_record.ot, 3);
+        assert_eq!(game.venue.as_ref().unwrap().name, "BB&T Center");
     });
 }
 
@@ -70,6 +330,85 @@ fn test_schedule_empty() {
     });
 }
 
+#[test]
+fn test_schedule_range() {
+    task::block_on(async {
+        let client = NhlClient::default();
+        let start = NaiveDate::from_ymd_opt(2019, 11, 27).unwrap();
+        let end = NaiveDate::from_ymd_opt(2019, 12, 1).unwrap();
+
+        let _m = mock("GET", "/schedule")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("startDate".into(), "2019-11-27".into()),
+                Matcher::UrlEncoded("endDate".into(), "2019-12-01".into()),
+                Matcher::UrlEncoded("teamId".into(), "5".into()),
+                Matcher::UrlEncoded("opponentId".into(), "29".into()),
+                Matcher::UrlEncoded("gameType".into(), "R".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/nhl/schedule_range.json")
+            .create();
+
+        let query = ScheduleQuery::new()
+            .date_range(start, end)
+            .team(5)
+            .opponent(29)
+            .game_type("R");
+        let resp = client.get_schedule(query).await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let dates = resp.unwrap();
+        assert_eq!(dates.len(), 3);
+        assert_eq!(
+            dates[1].date,
+            NaiveDate::from_ymd_opt(2019, 11, 29).unwrap()
+        );
+        assert!(dates
+            .iter()
+            .all(|d| d.games.iter().all(|g| g.teams.home.detail.id == 5)));
+    });
+}
+
+#[test]
+fn test_schedule_expanded() {
+    task::block_on(async {
+        let client = NhlClient::default();
+        let date = NaiveDate::from_ymd_opt(2019, 11, 29).unwrap();
+
+        let _m = mock("GET", "/schedule")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("startDate".into(), "2019-11-29".into()),
+                Matcher::UrlEncoded("endDate".into(), "2019-11-29".into()),
+                Matcher::UrlEncoded(
+                    "expand".into(),
+                    "schedule.linescore,schedule.broadcasts.all,schedule.teams,schedule.ticket"
+                        .into(),
+                ),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/nhl/schedule_hydrated.json")
+            .create();
+
+        let query = ScheduleQuery::new()
+            .date(date)
+            .expand(ScheduleExpand::Linescore)
+            .expand(ScheduleExpand::Broadcasts)
+            .expand(ScheduleExpand::Teams)
+            .expand(ScheduleExpand::Tickets);
+        let resp = client.get_schedule(query).await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let game = &resp.unwrap()[0].games[0];
+        assert_eq!(game.linescore.as_ref().unwrap().current_period, 3);
+        assert_eq!(game.broadcasts[1].r#type, "home");
+        assert_eq!(game.teams.home.detail.abbreviation.as_deref(), Some("PIT"));
+        assert_eq!(game.tickets.len(), 2);
+        assert_eq!(game.venue.as_ref().unwrap().name, "PPG Paints Arena");
+    });
+}
+
 #[test]
 fn test_game_content_pre() {
     task::block_on(async {
@@ -135,5 +474,623 @@ fn test_game_linescore_post() {
 
         let resp = client.get_game_linescore(game_pk).await;
         assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let linescore = resp.unwrap();
+        assert_eq!(linescore.current_period_ordinal.as_deref(), Some("3rd"));
+        assert_eq!(linescore.periods.len(), 3);
+        assert_eq!(linescore.periods[1].home.goals, 3);
+        assert_eq!(linescore.periods[1].away.shots_on_goal, 16);
+        assert!(!linescore.has_shootout);
+        assert_eq!(linescore.teams.home.shots_on_goal, 30);
+        assert_eq!(linescore.teams.away.num_skaters, 5);
+        assert_eq!(linescore.power_play_strength.as_deref(), Some("Even"));
+        assert!(!linescore.power_play_info.unwrap().in_situation);
+    });
+}
+
+#[test]
+fn test_client_builder() {
+    task::block_on(async {
+        let client = NhlClientBuilder::new()
+            .base_url(format!("{}/", mockito::server_url()))
+            .user_agent("stats-api-test")
+            .timeout(Duration::from_secs(5))
+            .connect_timeout(Duration::from_secs(1))
+            .max_connections(4)
+            .max_connections_per_host(2)
+            .build()
+            .unwrap();
+
+        let team_id = 1;
+        let _m = mock("GET", "/teams/1")
+            .match_header("user-agent", "stats-api-test")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/nhl/team.json")
+            .create();
+
+        let resp = client.get_team(team_id).await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+    });
+}
+
+#[test]
+fn test_client_builder_invalid_url() {
+    for base in &["statsapi.web.nhl.com/api/v1", "not a url"] {
+        match NhlClientBuilder::new().base_url(*base).build() {
+            Err(Error::InvalidUrl(url)) => assert_eq!(url, *base),
+            resp => panic!("Expected invalid url error, got {:?}", resp.map(|_| ())),
+        }
+    }
+}
+
+#[test]
+fn test_json_error_path() {
+    task::block_on(async {
+        let client = NhlClient::default();
+
+        let game_pk = 2_019_020_402;
+        let _m = mock("GET", "/game/2019020402/linescore")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body(r#"{"currentPeriod": 3, "teams": {"home": {"team": {"id": "1"}}}}"#)
+            .create();
+
+        match client.get_game_linescore(game_pk).await {
+            Err(Error::Json { path, .. }) => assert_eq!(path, "teams.home.team.id"),
+            resp => panic!("Expected json error, got {:?}", resp),
+        }
+    });
+}
+
+#[test]
+fn test_game_linescore_not_found() {
+    task::block_on(async {
+        let client = NhlClient::default();
+
+        let game_pk = 2_019_029_999;
+        let _m = mock("GET", "/game/2019029999/linescore")
+            .with_status(404)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body(r#"{"messageNumber": 2, "message": "Game data couldn't be found"}"#)
+            .create();
+
+        match client.get_game_linescore(game_pk).await {
+            Err(Error::NotFound(e)) => {
+                assert_eq!(e.code, 404);
+                assert_eq!(e.message_number, Some(2));
+                assert_eq!(e.message.as_deref(), Some("Game data couldn't be found"));
+            }
+            resp => panic!("Expected not found error, got {:?}", resp),
+        }
+    });
+}
+
+#[test]
+fn test_game_feed() {
+    task::block_on(async {
+        let client = NhlClient::default();
+
+        let game_pk = 2_019_020_401;
+        let _m = mock("GET", "/game/2019020401/feed/live")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/nhl/game_feed.json")
+            .create();
+
+        let resp = client.get_game_feed(game_pk).await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let feed = resp.unwrap();
+        assert_eq!(feed.game_data.teams.home.id, 29);
+        assert_eq!(
+            feed.game_data.players["ID8475793"].full_name,
+            "Sidney Crosby"
+        );
+
+        let plays = &feed.live_data.plays;
+        assert_eq!(plays.scoring().count(), 2);
+        assert!(plays
+            .scoring()
+            .all(|play| play.result.event_type_id == EventType::Goal));
+        assert_eq!(
+            plays.penalties().next().unwrap().result.penalty_minutes,
+            Some(2)
+        );
+        assert_eq!(plays.all_plays[3].coordinates.x, Some(71.0));
+
+        let home = &feed.live_data.boxscore.teams.home;
+        let goalie = home.players["ID8476432"].stats.goalie_stats.as_ref();
+        assert_eq!(goalie.unwrap().saves, 29);
+        assert_eq!(
+            feed.live_data.decisions.winner.as_ref().unwrap().id,
+            8_476_432
+        );
+    });
+}
+
+#[test]
+fn test_game_feed_events() {
+    let body = std::fs::read_to_string("./src/tests/responses/nhl/game_feed.json").unwrap();
+    let feed = serde_json::from_str::<GameFeedResponse>(&body).unwrap();
+
+    let mut previous = feed.clone();
+    previous.live_data.plays.all_plays.truncate(9);
+    previous.live_data.linescore.teams.home.goals = 4;
+    previous.live_data.linescore.current_period = 2;
+    previous.game_data.status.abstract_game_state = AbstractGameState::Live;
+    previous.game_data.status.detailed_state = DetailedState::InProgress;
+
+    let events = feed.events_since(&previous);
+    assert_eq!(events.len(), 6);
+    match &events[0] {
+        GameEvent::Play(play) => assert_eq!(play.result.event_type_id, EventType::Goal),
+        other => panic!("expected play, got {:?}", other),
+    }
+    match &events[3] {
+        GameEvent::Score { away, home } => assert_eq!((*away, *home), (2, 5)),
+        other => panic!("expected score, got {:?}", other),
+    }
+    match &events[4] {
+        GameEvent::Period { period } => assert_eq!(*period, 3),
+        other => panic!("expected period, got {:?}", other),
+    }
+    match &events[5] {
+        GameEvent::Status(status) => assert!(status.is_final()),
+        other => panic!("expected status, got {:?}", other),
+    }
+
+    assert!(feed.events_since(&feed).is_empty());
+}
+
+#[test]
+fn test_watch_game() {
+    task::block_on(async {
+        let client = NhlClient::default();
+
+        let _m = mock("GET", "/game/2019020401/feed/live")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/nhl/game_feed.json")
+            .create();
+
+        let events = client
+            .watch_game(2_019_020_401, Duration::from_secs(5))
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(events.len(), 1);
+        match &events[0] {
+            Ok(GameEvent::Status(status)) => assert!(status.is_final()),
+            other => panic!("expected final status, got {:?}", other),
+        }
+    });
+}
+
+#[test]
+fn test_watch_game_postponed() {
+    task::block_on(async {
+        let client = NhlClient::default();
+
+        let _m = mock("GET", "/game/2019020401/feed/live")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/nhl/game_feed_postponed.json")
+            .create();
+
+        let events = client
+            .watch_game(2_019_020_401, Duration::from_secs(5))
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(events.len(), 1);
+        match &events[0] {
+            Ok(GameEvent::Status(status)) => {
+                assert!(status.is_postponed());
+                assert!(status.is_over());
+            }
+            other => panic!("expected postponed status, got {:?}", other),
+        }
+    });
+}
+
+#[test]
+fn test_update_game_feed() {
+    task::block_on(async {
+        let client = NhlClient::default();
+
+        let _m = mock("GET", "/game/2019020401/feed/live")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/nhl/game_feed.json")
+            .create();
+        let _diff = mock("GET", "/game/2019020401/feed/live/diffPatch")
+            .match_query(Matcher::UrlEncoded(
+                "startTimecode".into(),
+                "20191130_023812".into(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/nhl/game_feed_diff.json")
+            .create();
+
+        let mut live_feed = client.get_live_game_feed(2_019_020_401).await.unwrap();
+        let resp = client.update_game_feed(&mut live_feed).await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        assert_eq!(live_feed.time_stamp(), Some("20191130_023822"));
+        let linescore = &live_feed.feed().live_data.linescore;
+        assert_eq!(linescore.teams.away.shots_on_goal, 32);
+    });
+}
+
+#[test]
+fn test_game_boxscore() {
+    task::block_on(async {
+        let client = NhlClient::default();
+
+        let game_pk = 2_019_020_401;
+        let _m = mock("GET", "/game/2019020401/boxscore")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/nhl/game_boxscore.json")
+            .create();
+
+        let resp = client.get_game_boxscore(game_pk).await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let boxscore = resp.unwrap();
+        let away = &boxscore.teams.away;
+        let crosby = away.players["ID8475793"].stats.skater_stats.as_ref();
+        let crosby = crosby.unwrap();
+        assert_eq!(crosby.time_on_ice, "20:41");
+        assert_eq!(crosby.plus_minus, -1);
+        assert_eq!(crosby.face_off_wins, 12);
+        assert_eq!(away.coaches[0].person.full_name, "Mike Sullivan");
+        assert_eq!(boxscore.officials.len(), 2);
+    });
+}
+
+#[test]
+fn test_standings() {
+    task::block_on(async {
+        let client = NhlClient::default();
+
+        let _m = mock("GET", "/standings")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("standingsType".into(), "wildCardWithLeaders".into()),
+                Matcher::UrlEncoded("season".into(), "20192020".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/nhl/standings.json")
+            .create();
+
+        let resp = client
+            .get_standings(Some("20192020"), StandingsType::WildCardWithLeaders)
+            .await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let records = resp.unwrap();
+        assert_eq!(records[0].standings_type, Some(StandingsType::WildCard));
+        assert!(records[0].division.is_none());
+
+        let columbus = &records[0].team_records[0];
+        assert_eq!(columbus.team.id, 29);
+        assert_eq!(columbus.points, 81);
+        assert_eq!(columbus.wild_card_rank, Some(1));
+        assert_eq!(columbus.league_record.ot, 15);
+
+        let washington = &records[1].team_records[0];
+        assert_eq!(washington.division_rank, Some(1));
+        assert_eq!(washington.clinch_indicator.as_deref(), Some("x"));
+    });
+}
+
+#[test]
+fn test_team_roster() {
+    task::block_on(async {
+        let client = NhlClient::default();
+
+        let team_id = 1;
+        let _m = mock("GET", "/teams/1/roster")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/nhl/team_roster.json")
+            .create();
+
+        let resp = client.get_team_roster(team_id, None).await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let roster = resp.unwrap();
+        assert_eq!(roster.len(), 10);
+        assert_eq!(roster[6].person.full_name, "Jack Hughes");
+        assert_eq!(roster[6].jersey_number.as_deref(), Some("86"));
+        assert_eq!(roster[6].position.code, "C");
+    });
+}
+
+#[test]
+fn test_team_with_roster() {
+    task::block_on(async {
+        let client = NhlClient::default();
+
+        let team_id = 1;
+        let _m = mock("GET", "/teams/1?expand=team.roster")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/nhl/team_with_roster.json")
+            .create();
+
+        let resp = client.get_team_with_roster(team_id).await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let roster = resp.unwrap().roster.unwrap().roster;
+        assert_eq!(roster.len(), 10);
+    });
+}
+
+#[test]
+fn test_person() {
+    task::block_on(async {
+        let client = NhlClient::default();
+
+        let person_id = 8_475_793;
+        let _m = mock("GET", "/people/8475793")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/nhl/person.json")
+            .create();
+
+        let resp = client.get_person(person_id).await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let person = resp.unwrap();
+        assert_eq!(person.birth_date, NaiveDate::from_ymd_opt(1987, 8, 7));
+        assert_eq!(person.shoots_catches.as_deref(), Some("L"));
+        assert_eq!(person.primary_position.as_ref().unwrap().code, "C");
+        assert_eq!(person.current_team.as_ref().unwrap().id, 5);
+        assert!(person.captain);
+        assert_eq!(
+            person.headshot_url(),
+            "https://cms.nhl.bamgrid.com/images/headshots/current/168x168/8475793.jpg"
+        );
+    });
+}
+
+#[test]
+fn test_people() {
+    task::block_on(async {
+        let client = NhlClient::default();
+
+        let _m1 = mock("GET", "/people/8475793")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/nhl/person.json")
+            .create();
+        let _m2 = mock("GET", "/people/8476432")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/nhl/person_8476432.json")
+            .create();
+
+        let resp = client.get_people(&[8_475_793, 8_476_432]).await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let people = resp.unwrap();
+        assert_eq!(people[0].id, 8_475_793);
+        assert_eq!(people[1].full_name, "Joonas Korpisalo");
+    });
+}
+
+#[test]
+fn test_person_stats_skater() {
+    task::block_on(async {
+        let client = NhlClient::default();
+
+        let _m = mock("GET", "/people/8471675/stats")
+            .match_query(Matcher::UrlEncoded("stats".into(), "yearByYear".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/nhl/person_stats_skater.json")
+            .create();
+
+        let query = StatsQuery::new(StatsType::YearByYear);
+        let resp = client.get_person_stats(8_471_675, query).await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let stats = resp.unwrap();
+        assert_eq!(stats[0].r#type.display_name, Some(StatsType::YearByYear));
+
+        let split = &stats[0].splits[1];
+        assert_eq!(split.season.as_deref(), Some("20182019"));
+        match &split.stat {
+            PlayerStats::Skater(stat) => {
+                assert_eq!(stat.goals, 35);
+                assert_eq!(stat.points, 100);
+                assert_eq!(stat.plus_minus, 18);
+            }
+            other => panic!("expected skater stats, got {:?}", other),
+        }
+    });
+}
+
+#[test]
+fn test_person_stats_goalie() {
+    task::block_on(async {
+        let client = NhlClient::default();
+
+        let _m = mock("GET", "/people/8476914/stats")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("stats".into(), "gameLog".into()),
+                Matcher::UrlEncoded("season".into(), "20192020".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/nhl/person_stats_goalie.json")
+            .create();
+
+        let query = StatsQuery::new(StatsType::GameLog).season("20192020");
+        let resp = client.get_person_stats(8_476_914, query).await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let split = &resp.unwrap()[0].splits[1];
+        assert_eq!(split.date, NaiveDate::from_ymd_opt(2019, 11, 27));
+        assert_eq!(split.is_ot, Some(true));
+        match &split.stat {
+            PlayerStats::Goalie(stat) => {
+                assert_eq!(stat.saves, 30);
+                assert_eq!(stat.goals_against, 4);
+                assert_eq!(stat.decision.as_deref(), Some("L"));
+            }
+            other => panic!("expected goalie stats, got {:?}", other),
+        }
+    });
+}
+
+#[test]
+fn test_person_stats_error_path() {
+    task::block_on(async {
+        let client = NhlClient::default();
+
+        let _m = mock("GET", "/people/8476914/stats")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body(
+                r#"{"stats": [{
+                    "type": {"displayName": "gameLog"},
+                    "splits": [{"stat": {"saves": 30, "shotsAgainst": "x"}}]
+                }]}"#,
+            )
+            .create();
+
+        let query = StatsQuery::new(StatsType::GameLog);
+        match client.get_person_stats(8_476_914, query).await {
+            Err(Error::Json { path, source }) => {
+                assert_eq!(path, "stats[0].splits[0].stat.shotsAgainst");
+                assert!(
+                    source
+                        .to_string()
+                        .starts_with("invalid type: string \"x\", expected u32"),
+                    "{}",
+                    source
+                );
+            }
+            resp => panic!("Expected json error, got {:?}", resp),
+        }
+    });
+}
+
+#[test]
+fn test_team_stats() {
+    task::block_on(async {
+        let client = NhlClient::default();
+
+        let _m = mock("GET", "/teams/5/stats")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/nhl/team_stats.json")
+            .create();
+
+        let resp = client.get_team_stats(5, None).await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let stats = resp.unwrap();
+        match &stats[0] {
+            TeamStats::Season(splits) => {
+                assert_eq!(splits[0].stat.pts, 100);
+                assert_eq!(splits[0].stat.pt_pctg, Some(61.0));
+            }
+            other => panic!("expected season stats, got {:?}", other),
+        }
+        match &stats[1] {
+            TeamStats::Rankings(splits) => {
+                assert_eq!(splits[0].stat.ot, Some(2));
+                assert_eq!(splits[0].stat.penalty_kill_percentage, Some(23));
+            }
+            other => panic!("expected rankings, got {:?}", other),
+        }
+    });
+}
+
+#[test]
+fn test_team_stats_other_type() {
+    task::block_on(async {
+        let client = NhlClient::default();
+
+        let _m = mock("GET", "/teams/5/stats")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body(
+                r#"{"stats": [{
+                    "type": {"displayName": "playoffStatRankings"},
+                    "splits": [{"stat": {"wins": "3rd"}}]
+                }]}"#,
+            )
+            .create();
+
+        let resp = client.get_team_stats(5, None).await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        match &resp.unwrap()[0] {
+            TeamStats::Other { stats_type, splits } => {
+                assert_eq!(stats_type, "playoffStatRankings");
+                assert_eq!(splits[0]["stat"]["wins"], "3rd");
+            }
+            other => panic!("expected other stats, got {:?}", other),
+        }
+    });
+}
+
+#[test]
+fn test_team_stats_error_path() {
+    task::block_on(async {
+        let client = NhlClient::default();
+
+        let _m = mock("GET", "/teams/5/stats")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body(
+                r#"{"stats": [{
+                    "type": {"displayName": "statsSingleSeason"},
+                    "splits": [{"stat": {"gamesPlayed": "x"}}]
+                }]}"#,
+            )
+            .create();
+
+        match client.get_team_stats(5, None).await {
+            Err(Error::Json { path, .. }) => {
+                assert_eq!(path, "stats[0].splits[0].stat.gamesPlayed")
+            }
+            resp => panic!("Expected json error, got {:?}", resp),
+        }
+    });
+}
+
+#[test]
+fn test_leaders() {
+    task::block_on(async {
+        let client = NhlClient::default();
+
+        let _m = mock("GET", "/stats/leaders")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("leaderCategories".into(), "savePct".into()),
+                Matcher::UrlEncoded("limit".into(), "2".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./src/tests/responses/nhl/leaders.json")
+            .create();
+
+        let resp = client
+            .get_leaders(LeaderCategory::SavePercentage, None, 2)
+            .await;
+        assert!(resp.is_ok(), "{}", resp.err().unwrap());
+
+        let leaders = resp.unwrap();
+        assert_eq!(leaders[0].person.full_name, "Tuukka Rask");
+        assert_eq!(leaders[0].value, "0.937");
+        assert_eq!(leaders[1].rank, 2);
     });
 }